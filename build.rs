@@ -0,0 +1,26 @@
+// Bakes the build version into FB_BUILD_VERSION for `PROGRAM_VERSION`.
+//
+// The crate version comes from Cargo; when built from a git checkout the short
+// commit hash is appended as build metadata, e.g. "0.1.0+1a2b3c4".
+
+use std::process::Command;
+
+fn main() {
+    let mut version = std::env::var("CARGO_PKG_VERSION").unwrap();
+
+    let hash = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok());
+    if let Some(hash) = hash.as_deref().map(str::trim).filter(|hash| !hash.is_empty()) {
+        version.push('+');
+        version.push_str(hash);
+    }
+
+    println!("cargo:rustc-env=FB_BUILD_VERSION={}", version);
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=.git/HEAD");
+    println!("cargo:rerun-if-changed=.git/refs/heads");
+}
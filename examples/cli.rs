@@ -0,0 +1,146 @@
+// Command-line client for a deployed tracker, without recompiling for each program id
+//
+// Usage: cargo run --example cli --features client-rpc -- <PROGRAM_ID> <init|play|status> [RPC_URL]
+
+use counter_program::{
+    client::{ensure_funded, get_tracker_address, initialize_tracker, play_season, submit_with_retry},
+    state::FenerbahceTracker,
+};
+use solana_client::rpc_client::RpcClient;
+use solana_program::{instruction::Instruction, pubkey::Pubkey};
+use solana_sdk::{
+    commitment_config::CommitmentConfig,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+use std::str::FromStr;
+use std::time::Duration;
+
+const USAGE: &str = "usage: cli <PROGRAM_ID> <init|play|status> [RPC_URL]";
+const DEFAULT_RPC_URL: &str = "http://127.0.0.1:8899";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Command {
+    Init,
+    Play,
+    Status,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Args {
+    program_id: Pubkey,
+    command: Command,
+    rpc_url: String,
+}
+
+/// Parse the arguments after the binary name
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Args, String> {
+    let mut args = args.into_iter();
+    let program_id = args.next().ok_or(USAGE)?;
+    let program_id = Pubkey::from_str(&program_id).map_err(|_| format!("invalid program id: {}", program_id))?;
+    let command = match args.next().ok_or(USAGE)?.as_str() {
+        "init" => Command::Init,
+        "play" => Command::Play,
+        "status" => Command::Status,
+        other => return Err(format!("unknown command: {}\n{}", other, USAGE)),
+    };
+    let rpc_url = args.next().unwrap_or_else(|| DEFAULT_RPC_URL.to_string());
+    if args.next().is_some() {
+        return Err(USAGE.to_string());
+    }
+
+    Ok(Args { program_id, command, rpc_url })
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(message) => {
+            eprintln!("{}", message);
+            std::process::exit(2);
+        }
+    };
+
+    let rpc_client = RpcClient::new_with_commitment(args.rpc_url.clone(), CommitmentConfig::confirmed());
+    let tracker_pubkey = get_tracker_address(&args.program_id);
+
+    match args.command {
+        Command::Status => print_status(&rpc_client, &tracker_pubkey),
+        Command::Init | Command::Play => {
+            let payer = Keypair::new();
+            ensure_funded(&rpc_client, &payer.pubkey(), 1_000_000_000)?; // 1 SOL
+
+            let ix = match args.command {
+                Command::Init => initialize_tracker(&args.program_id, &tracker_pubkey, &payer.pubkey()),
+                _ => play_season(&args.program_id, &tracker_pubkey),
+            };
+            let signature = send(&rpc_client, &payer, ix)?;
+            println!("✅ Transaction: {}", signature);
+            print_status(&rpc_client, &tracker_pubkey)
+        }
+    }
+}
+
+fn send(
+    rpc_client: &RpcClient,
+    payer: &Keypair,
+    ix: Instruction,
+) -> Result<solana_sdk::signature::Signature, Box<dyn std::error::Error>> {
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&payer.pubkey()),
+        &[payer],
+        rpc_client.get_latest_blockhash()?,
+    );
+    Ok(submit_with_retry(rpc_client, &tx, 5, Duration::from_millis(500))?)
+}
+
+fn print_status(rpc_client: &RpcClient, tracker_pubkey: &Pubkey) -> Result<(), Box<dyn std::error::Error>> {
+    let account_data = match rpc_client.get_account_data(tracker_pubkey) {
+        Ok(data) => data,
+        Err(_) => {
+            println!("❌ No tracker at {}, run the init command first", tracker_pubkey);
+            return Ok(());
+        }
+    };
+    let tracker = FenerbahceTracker::unpack(&account_data)?;
+
+    println!("📊 Tracker {}", tracker_pubkey);
+    println!("   Current season: {}", tracker.get_season_string());
+    println!("   Total trophies: {}", tracker.total_trophies);
+    println!("   Seasons played: {}", tracker.seasons_played);
+    if tracker.is_season_complete() {
+        println!("   🏁 All seasons completed");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let program_id = Pubkey::new_unique();
+        let id = program_id.to_string();
+
+        let parsed = parse_args(args(&[&id, "play"])).unwrap();
+        assert_eq!(
+            parsed,
+            Args { program_id, command: Command::Play, rpc_url: DEFAULT_RPC_URL.to_string() }
+        );
+
+        let parsed = parse_args(args(&[&id, "status", "https://api.devnet.solana.com"])).unwrap();
+        assert_eq!(parsed.command, Command::Status);
+        assert_eq!(parsed.rpc_url, "https://api.devnet.solana.com");
+
+        assert!(parse_args(args(&[&id, "replay"])).unwrap_err().starts_with("unknown command: replay"));
+        assert!(parse_args(args(&["not-a-pubkey", "init"])).is_err());
+        assert!(parse_args(args(&[&id])).is_err());
+        assert!(parse_args(args(&[&id, "init", DEFAULT_RPC_URL, "extra"])).is_err());
+    }
+}
@@ -2,7 +2,7 @@
 // Starting from 2010-2011 season with 17 trophies
 
 use counter_program::{
-    client::{get_tracker_address, initialize_tracker},
+    client::{ensure_funded, get_tracker_address, initialize_tracker, required_init_lamports},
     state::FenerbahceTracker,
 };
 use solana_program::pubkey::Pubkey;
@@ -13,9 +13,6 @@ use solana_sdk::{
      transaction::Transaction,
 };
 use std::str::FromStr;
-use std::thread;
-use std::time::Duration;
-use borsh::BorshDeserialize;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("🟡🔵 Fenerbahçe Championship Tracker Initialization");
@@ -31,19 +28,17 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let payer = Keypair::new();
     let tracker_pubkey = get_tracker_address(&program_id);
     
-    // Fund the payer account
-    println!("💰 Requesting airdrop for payer account: {}", payer.pubkey());
+    // Fund the payer account with the tracker's rent plus headroom for transaction fees
+    let rent_lamports = required_init_lamports(&rpc_client)
+        .expect("Failed to get rent-exempt minimum");
+    let funding_lamports = rent_lamports + 10_000;
+    println!("💰 Funding payer account {} with at least {} lamports", payer.pubkey(), funding_lamports);
     
-    let airdrop_signature = rpc_client
-        .request_airdrop(&payer.pubkey(), 1_000_000_000) // 1 SOL
-        .expect("Failed to request airdrop");
-    
-    rpc_client.confirm_transaction(&airdrop_signature)
-        .expect("Failed to confirm airdrop");
-    println!("✅ Airdrop confirmed");
-    
-    // Wait a bit to ensure the account is funded
-    thread::sleep(Duration::from_secs(2));
+    // Only the shortfall is requested, so an already funded payer doesn't use up the faucet
+    match ensure_funded(&rpc_client, &payer.pubkey(), funding_lamports).expect("Failed to fund payer") {
+        Some(_) => println!("✅ Airdrop confirmed"),
+        None => println!("✅ Payer already funded, no airdrop needed"),
+    }
     
     // Check balance
     let balance = rpc_client.get_balance(&payer.pubkey())
@@ -57,7 +52,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Check if tracker already exists
     match rpc_client.get_account(&tracker_pubkey) {
         Ok(account) => {
-            let tracker_data = FenerbahceTracker::try_from_slice(&account.data)?;
+            let tracker_data = FenerbahceTracker::unpack(&account.data)?;
             println!("⚠️  Fenerbahçe tracker already exists!");
             println!("   Current season: {}", tracker_data.get_season_string());
             println!("   Total trophies: {}", tracker_data.total_trophies);
@@ -93,13 +88,16 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Read initial tracker data
     let account_data = rpc_client.get_account_data(&tracker_pubkey)
         .expect("Failed to get account data");
-    let tracker_data = FenerbahceTracker::try_from_slice(&account_data)
+    let tracker_data = FenerbahceTracker::unpack(&account_data)
         .expect("Failed to deserialize tracker data");
     
     println!("\n🏆 Fenerbahçe Championship Tracker Initialized!");
     println!("   🗓️  Starting season: {}", tracker_data.get_season_string());
     println!("   🏆 Initial trophies: {}", tracker_data.total_trophies);
-    println!("   📊 Seasons to play: 2010-2011 to 2024-2025 (15 seasons)");
+    println!("   📊 Seasons to play: {}-{} to {}-{} ({} seasons)",
+             FenerbahceTracker::STARTING_SEASON, FenerbahceTracker::STARTING_SEASON + 1,
+             FenerbahceTracker::ENDING_SEASON, FenerbahceTracker::ENDING_SEASON + 1,
+             tracker_data.remaining_seasons());
     println!("   🎯 Run client_playseason to find out the champions!");
     println!("   📍 Tracker PDA address: {}", tracker_pubkey);
     println!("\n   Run client_playseason.rs to start playing seasons!");
@@ -0,0 +1,95 @@
+// This example fast-forwards the Fenerbahçe tracker to the next championship in one transaction
+// PlayUntilTitle can play many seasons at once, so it asks for a larger compute budget up front
+
+use counter_program::{
+    client::{ensure_funded, get_tracker_address, play_until_title, submit_with_retry, with_compute_budget_prefix},
+    state::FenerbahceTracker,
+};
+use solana_program::pubkey::Pubkey;
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::{
+     commitment_config::CommitmentConfig,
+     signature::{Keypair, Signer},
+     transaction::Transaction,
+};
+use std::str::FromStr;
+use std::time::Duration;
+
+// Room for every remaining season in a single PlayUntilTitle call
+const COMPUTE_UNITS: u32 = 400_000;
+// Priority fee in micro-lamports per compute unit
+const COMPUTE_UNIT_PRICE: u64 = 1_000;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    println!("🟡🔵 Fenerbahçe Fast Forward");
+
+    // Connect to Solana devnet cluster
+    let rpc_client = RpcClient::new_with_commitment(
+        "http://127.0.0.1:8899".to_string(),
+        CommitmentConfig::confirmed(),
+    );
+
+    let program_id: Pubkey = Pubkey::from_str("FWTvPqvnxNMserrz39P33H1LfnfokHqkrM7k2TfoYn7d").unwrap();
+    let payer = Keypair::new();
+    let tracker_pubkey = get_tracker_address(&program_id);
+
+    // Fund the payer account
+    println!("💰 Funding payer account: {}", payer.pubkey());
+    match ensure_funded(&rpc_client, &payer.pubkey(), 1_000_000_000).expect("Failed to fund payer") { // 1 SOL
+        Some(_) => println!("✅ Airdrop confirmed"),
+        None => println!("✅ Payer already funded, no airdrop needed"),
+    }
+
+    let account = match rpc_client.get_account(&tracker_pubkey) {
+        Ok(account) => account,
+        Err(_) => {
+            println!("❌ Fenerbahçe tracker doesn't exist!");
+            println!("   Run client_init.rs first to initialize the tracker");
+            return Ok(());
+        }
+    };
+
+    let tracker_data = FenerbahceTracker::unpack(&account.data)?;
+    if tracker_data.is_season_complete() {
+        println!("🏁 All seasons completed! Final trophy count: {}", tracker_data.total_trophies);
+        return Ok(());
+    }
+
+    println!("⏩ Fast-forwarding from {} ({} trophies)",
+             tracker_data.get_season_string(), tracker_data.total_trophies);
+
+    let instructions = with_compute_budget_prefix(
+        COMPUTE_UNITS,
+        COMPUTE_UNIT_PRICE,
+        &[play_until_title(&program_id, &tracker_pubkey)],
+    );
+    let transaction = Transaction::new_signed_with_payer(
+        &instructions,
+        Some(&payer.pubkey()),
+        &[&payer],
+        rpc_client.get_latest_blockhash()
+            .expect("Failed to get latest blockhash"),
+    );
+
+    let signature = submit_with_retry(&rpc_client, &transaction, 5, Duration::from_millis(500))
+        .expect("Failed to play until title");
+    println!("✅ Done! Transaction: {}", signature);
+
+    let account_data = rpc_client.get_account_data(&tracker_pubkey)
+        .expect("Failed to get account data");
+    let updated_tracker = FenerbahceTracker::unpack(&account_data)
+        .expect("Failed to deserialize tracker data");
+
+    println!("\n📈 Updated Status:");
+    println!("   Seasons played this run: {}", updated_tracker.seasons_played - tracker_data.seasons_played);
+    println!("   Total trophies: {}", updated_tracker.total_trophies);
+    if updated_tracker.total_trophies > tracker_data.total_trophies {
+        if let Some(season) = updated_tracker.last_played_season() {
+            println!("   🏆 Title won in {}", season.season);
+        }
+    } else {
+        println!("   😞 No more titles before the end of the schedule");
+    }
+
+    Ok(())
+}
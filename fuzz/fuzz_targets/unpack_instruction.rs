@@ -0,0 +1,22 @@
+// Feeds arbitrary instruction data to FenerbahceInstruction::unpack
+//
+// unpack must never panic, and may only accept an input that is the canonical encoding of
+// what it returned, or a bare discriminator standing for the default argument.
+
+#![no_main]
+
+use counter_program::instruction::FenerbahceInstruction;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(instruction) = FenerbahceInstruction::unpack(data) {
+        let canonical = instruction.pack();
+        let bare_default = data.len() == 1 && canonical == [data[0], 0];
+        assert!(
+            data == canonical.as_slice() || bare_default,
+            "accepted {:?} as {:?}",
+            data,
+            instruction
+        );
+    }
+});
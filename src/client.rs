@@ -2,7 +2,29 @@ use solana_program::{
     instruction::{AccountMeta, Instruction},
     pubkey::Pubkey,
 };
-use crate::instruction::find_tracker_pda;
+#[cfg(feature = "client-rpc")]
+use solana_client::{
+    client_error::{ClientError, ClientErrorKind},
+    rpc_client::RpcClient,
+    rpc_filter::{Memcmp, RpcFilterType},
+};
+#[cfg(feature = "client-rpc")]
+use solana_sdk::{
+    compute_budget::ComputeBudgetInstruction,
+    signature::{Keypair, Signature, Signer},
+    transaction::Transaction,
+};
+#[cfg(feature = "client-rpc")]
+use std::time::Duration;
+use borsh::BorshDeserialize;
+use crate::{
+    event::TrophyEvent,
+    instruction::{find_season_log_pda, find_tracker_pda, FenerbahceInstruction},
+    processor::STATUS_EVENT,
+    state::{FenerbahceTracker, OverflowPolicy, ScoringMode, SeasonData, SeasonLogRecord},
+};
+#[cfg(feature = "client-rpc")]
+use crate::processor::TRACKER_ACCOUNT_SPACE;
 
 /// Creates an instruction to initialize Fenerbahçe tracker
 pub fn initialize_tracker(
@@ -10,9 +32,60 @@ pub fn initialize_tracker(
     tracker_account: &Pubkey,
     payer: &Pubkey,
 ) -> Instruction {
-    // Serialize the instruction data
-    let instruction_data = vec![0]; // Variant 0 for InitializeTracker
+    initialize_tracker_with_mode(program_id, tracker_account, payer, ScoringMode::default())
+}
+
+/// Creates an instruction to initialize Fenerbahçe tracker with a specific scoring mode
+pub fn initialize_tracker_with_mode(
+    program_id: &Pubkey,
+    tracker_account: &Pubkey,
+    payer: &Pubkey,
+    scoring_mode: ScoringMode,
+) -> Instruction {
+    let instruction = FenerbahceInstruction::InitializeTracker { scoring_mode };
+
+    initialize_tracker_instruction(program_id, tracker_account, payer, instruction)
+}
+
+/// Creates an instruction that initializes the tracker unless it already exists
+pub fn initialize_if_needed(
+    program_id: &Pubkey,
+    tracker_account: &Pubkey,
+    payer: &Pubkey,
+) -> Instruction {
+    let instruction = FenerbahceInstruction::InitializeIfNeeded { scoring_mode: ScoringMode::default() };
+
+    initialize_tracker_instruction(program_id, tracker_account, payer, instruction)
+}
+
+/// Creates an instruction that initializes the tracker and plays the first season in one go
+pub fn initialize_and_play(
+    program_id: &Pubkey,
+    tracker_account: &Pubkey,
+    payer: &Pubkey,
+) -> Instruction {
+    let instruction = FenerbahceInstruction::InitializeAndPlay { scoring_mode: ScoringMode::default() };
+
+    initialize_tracker_instruction(program_id, tracker_account, payer, instruction)
+}
 
+/// Creates an instruction upgrading a legacy 11-byte tracker, with `payer` covering the extra rent
+pub fn migrate_tracker(
+    program_id: &Pubkey,
+    tracker_account: &Pubkey,
+    payer: &Pubkey,
+) -> Instruction {
+    // Same accounts as InitializeTracker: the payer funds the growth through the system program
+    initialize_tracker_instruction(program_id, tracker_account, payer, FenerbahceInstruction::MigrateTracker)
+}
+
+/// Shared account layout for the builders whose payer funds the tracker account
+fn initialize_tracker_instruction(
+    program_id: &Pubkey,
+    tracker_account: &Pubkey,
+    payer: &Pubkey,
+    instruction: FenerbahceInstruction,
+) -> Instruction {
     Instruction {
         program_id: *program_id,
         accounts: vec![
@@ -20,7 +93,7 @@ pub fn initialize_tracker(
             AccountMeta::new(*payer, true),            // Payer account (writable, signer)
             AccountMeta::new_readonly(solana_program::system_program::id(), false), // System program
         ],
-        data: instruction_data,
+        data: instruction.pack(),
     }
 }
 
@@ -29,7 +102,245 @@ pub fn play_season(
     program_id: &Pubkey,
     tracker_account: &Pubkey,
 ) -> Instruction {
-    let instruction_data = vec![1]; // Variant 1 for PlaySeason
+    play_season_with_policy(program_id, tracker_account, OverflowPolicy::default())
+}
+
+/// Creates an instruction to play a season, handling score overflow according to `overflow_policy`
+pub fn play_season_with_policy(
+    program_id: &Pubkey,
+    tracker_account: &Pubkey,
+    overflow_policy: OverflowPolicy,
+) -> Instruction {
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*tracker_account, false), // Tracker account (writable, not signer)
+        ],
+        data: FenerbahceInstruction::PlaySeason { overflow_policy }.pack(),
+    }
+}
+
+/// Creates an instruction to play a season, invoking `reward_program` if it brings a title
+///
+/// `reward_accounts` are forwarded to the reward program after the signing tracker PDA.
+/// The program must first be registered with `set_reward_program`.
+pub fn play_season_with_reward_hook(
+    program_id: &Pubkey,
+    tracker_account: &Pubkey,
+    reward_program: &Pubkey,
+    reward_accounts: Vec<AccountMeta>,
+) -> Instruction {
+    let mut instruction = play_season(program_id, tracker_account);
+    instruction.accounts.push(AccountMeta::new_readonly(*reward_program, false));
+    instruction.accounts.extend(reward_accounts);
+    instruction
+}
+
+/// Creates an instruction to play a season and append it to the season log, which `payer` funds
+pub fn play_season_logged(program_id: &Pubkey, tracker_account: &Pubkey, payer: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*tracker_account, false),                   // Tracker account (writable, not signer)
+            AccountMeta::new(get_season_log_address(program_id), false), // Season log PDA (writable, not signer)
+            AccountMeta::new(*payer, true),                              // Payer account (writable, signer)
+            AccountMeta::new_readonly(solana_program::system_program::id(), false), // System program
+        ],
+        data: FenerbahceInstruction::PlaySeasonLogged.pack(),
+    }
+}
+
+/// Creates an instruction proposing `new_authority` as the next tracker authority
+pub fn propose_authority(
+    program_id: &Pubkey,
+    tracker_account: &Pubkey,
+    current_authority: &Pubkey,
+    new_authority: &Pubkey,
+) -> Instruction {
+    let instruction_data = FenerbahceInstruction::ProposeAuthority { new_authority: *new_authority }.pack();
+
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*tracker_account, false),             // Tracker account (writable, not signer)
+            AccountMeta::new_readonly(*current_authority, true),   // Current authority (signer)
+        ],
+        data: instruction_data,
+    }
+}
+
+/// Creates an instruction for the pending authority to accept control of the tracker
+pub fn accept_authority(
+    program_id: &Pubkey,
+    tracker_account: &Pubkey,
+    pending_authority: &Pubkey,
+) -> Instruction {
+    let instruction_data = FenerbahceInstruction::AcceptAuthority.pack();
+
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*tracker_account, false),             // Tracker account (writable, not signer)
+            AccountMeta::new_readonly(*pending_authority, true),   // Pending authority (signer)
+        ],
+        data: instruction_data,
+    }
+}
+
+/// Creates an instruction for the authority to pause the tracker
+pub fn pause(program_id: &Pubkey, tracker_account: &Pubkey, authority: &Pubkey) -> Instruction {
+    set_paused_instruction(program_id, tracker_account, authority, FenerbahceInstruction::Pause)
+}
+
+/// Creates an instruction for the authority to unpause the tracker
+pub fn unpause(program_id: &Pubkey, tracker_account: &Pubkey, authority: &Pubkey) -> Instruction {
+    set_paused_instruction(program_id, tracker_account, authority, FenerbahceInstruction::Unpause)
+}
+
+/// Shared account layout for both pause builders
+fn set_paused_instruction(
+    program_id: &Pubkey,
+    tracker_account: &Pubkey,
+    authority: &Pubkey,
+    instruction: FenerbahceInstruction,
+) -> Instruction {
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*tracker_account, false),     // Tracker account (writable, not signer)
+            AccountMeta::new_readonly(*authority, true),   // Tracker authority (signer)
+        ],
+        data: instruction.pack(),
+    }
+}
+
+/// Creates an instruction for the authority to register the reward program, or clear it with `None`
+pub fn set_reward_program(
+    program_id: &Pubkey,
+    tracker_account: &Pubkey,
+    authority: &Pubkey,
+    reward_program: Option<Pubkey>,
+) -> Instruction {
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*tracker_account, false),     // Tracker account (writable, not signer)
+            AccountMeta::new_readonly(*authority, true),   // Tracker authority (signer)
+        ],
+        data: FenerbahceInstruction::SetRewardProgram { reward_program }.pack(),
+    }
+}
+
+/// Creates an instruction asking the program to log its build version
+pub fn get_version(program_id: &Pubkey) -> Instruction {
+    let instruction_data = FenerbahceInstruction::GetVersion.pack();
+
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![],
+        data: instruction_data,
+    }
+}
+
+/// Creates an instruction asking the program to log the tracker PDA and its bump
+pub fn get_pda(program_id: &Pubkey) -> Instruction {
+    let instruction_data = FenerbahceInstruction::GetPda.pack();
+
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![],
+        data: instruction_data,
+    }
+}
+
+/// Creates an instruction asking the program to log the stored data for `year`
+pub fn get_season(program_id: &Pubkey, year: u16) -> Instruction {
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![],
+        data: FenerbahceInstruction::GetSeason { year }.pack(),
+    }
+}
+
+/// Creates an instruction to preview the next season without changing state
+pub fn peek_next_season(
+    program_id: &Pubkey,
+    tracker_account: &Pubkey,
+) -> Instruction {
+    let instruction_data = FenerbahceInstruction::PeekNextSeason.pack();
+
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(*tracker_account, false), // Tracker account (read-only)
+        ],
+        data: instruction_data,
+    }
+}
+
+/// Creates an instruction asking the program to log the tracker's state hash
+///
+/// Compare the logged digest with `FenerbahceTracker::state_hash` of a cached copy to tell
+/// whether the tracker changed.
+pub fn state_hash(program_id: &Pubkey, tracker_account: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![AccountMeta::new_readonly(*tracker_account, false)],
+        data: FenerbahceInstruction::StateHash.pack(),
+    }
+}
+
+/// Creates an instruction asking the program to log the tracker's status
+pub fn status(program_id: &Pubkey, tracker_account: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![AccountMeta::new_readonly(*tracker_account, false)],
+        data: FenerbahceInstruction::Status.pack(),
+    }
+}
+
+/// Creates an instruction to verify the tracker against a replay of the season history
+pub fn verify_integrity(
+    program_id: &Pubkey,
+    tracker_account: &Pubkey,
+) -> Instruction {
+    let instruction_data = FenerbahceInstruction::VerifyIntegrity.pack();
+
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(*tracker_account, false), // Tracker account (read-only)
+        ],
+        data: instruction_data,
+    }
+}
+
+/// Creates an instruction to copy the tracker into a program-owned snapshot account
+pub fn snapshot(
+    program_id: &Pubkey,
+    tracker_account: &Pubkey,
+    snapshot_account: &Pubkey,
+    authority: &Pubkey,
+) -> Instruction {
+    let instruction_data = FenerbahceInstruction::Snapshot.pack();
+
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(*tracker_account, false), // Tracker account (read-only)
+            AccountMeta::new(*snapshot_account, false),         // Snapshot account (writable)
+            AccountMeta::new_readonly(*authority, true),        // Tracker authority (signer)
+        ],
+        data: instruction_data,
+    }
+}
+
+/// Creates an instruction to play seasons until the next title or the end of the schedule
+pub fn play_until_title(
+    program_id: &Pubkey,
+    tracker_account: &Pubkey,
+) -> Instruction {
+    let instruction_data = FenerbahceInstruction::PlayUntilTitle.pack();
 
     Instruction {
         program_id: *program_id,
@@ -40,12 +351,248 @@ pub fn play_season(
     }
 }
 
+/// Tracker state a PlaySeason on `tracker` should leave behind, predicted off-chain
+///
+/// Uses the processor's own season logic with the default `Checked` policy. A PlaySeason
+/// that fails (paused tracker, score overflow) or has no season left to play leaves the
+/// account as it was, so those cases return `tracker` unchanged. Diff the prediction
+/// against the fetched account after sending to catch a program that disagrees.
+pub fn simulate_play(tracker: &FenerbahceTracker) -> FenerbahceTracker {
+    let mut next = tracker.clone();
+    if next.paused || next.is_season_complete() || next.advance_season().is_err() {
+        return tracker.clone();
+    }
+    next
+}
+
+/// Pick the TrophyEvents out of a transaction's log data, in emission order
+///
+/// Each entry is one decoded `meta.logData` field. Entries from the program's other events,
+/// or from other programs, are skipped.
+pub fn decode_events(log_datas: &[Vec<u8>]) -> Vec<TrophyEvent> {
+    log_datas.iter().filter_map(|data| TrophyEvent::decode(data)).collect()
+}
+
+/// Tracker status as logged by the Status instruction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TrackerStatus {
+    pub remaining_seasons: u16,
+    pub current_season: u16,
+    pub total_trophies: u64,
+    /// League position in the next season, `None` once the schedule is complete
+    pub next_position: Option<u8>,
+    pub next_champion: bool,
+}
+
+/// Parse the fields of one Status event, `None` if they aren't one
+pub fn decode_status(fields: &[Vec<u8>]) -> Option<TrackerStatus> {
+    let [tag, remaining, season, trophies, position, champion] = fields else {
+        return None;
+    };
+    if tag.as_slice() != STATUS_EVENT {
+        return None;
+    }
+    let [position] = position.as_slice() else { return None };
+    let [champion] = champion.as_slice() else { return None };
+
+    Some(TrackerStatus {
+        remaining_seasons: u16::from_le_bytes(remaining.as_slice().try_into().ok()?),
+        current_season: u16::from_le_bytes(season.as_slice().try_into().ok()?),
+        total_trophies: u64::from_le_bytes(trophies.as_slice().try_into().ok()?),
+        next_position: (*position != 0).then_some(*position),
+        next_champion: *champion != 0,
+    })
+}
+
 /// Get the global Fenerbahçe tracker PDA address for this program
 pub fn get_tracker_address(program_id: &Pubkey) -> Pubkey {
     let (tracker_pda, _) = find_tracker_pda(program_id);
     tracker_pda
 }
 
+/// Get the season log PDA address PlaySeasonLogged appends to
+pub fn get_season_log_address(program_id: &Pubkey) -> Pubkey {
+    let (season_log_pda, _) = find_season_log_pda(program_id);
+    season_log_pda
+}
+
+/// Records in a season log account's data, oldest first
+///
+/// A trailing partial record is ignored.
+pub fn decode_season_log(data: &[u8]) -> Vec<SeasonLogRecord> {
+    data.chunks_exact(SeasonLogRecord::SIZE)
+        .filter_map(|chunk| SeasonLogRecord::try_from_slice(chunk).ok())
+        .collect()
+}
+
+/// Lamports the tracker account needs to be rent-exempt, as reported by the cluster
+#[cfg(feature = "client-rpc")]
+#[allow(clippy::result_large_err)] // Mirrors the RpcClient API, which returns ClientError unboxed
+pub fn required_init_lamports(rpc: &RpcClient) -> Result<u64, ClientError> {
+    rpc.get_minimum_balance_for_rent_exemption(TRACKER_ACCOUNT_SPACE)
+}
+
+/// `getProgramAccounts` filters matching tracker accounts at the current schema version
+///
+/// Pass them as `RpcProgramAccountsConfig { filters: Some(program_account_filters()), .. }`
+/// to `RpcClient::get_program_accounts_with_config`.
+#[cfg(feature = "client-rpc")]
+pub fn program_account_filters() -> Vec<RpcFilterType> {
+    vec![
+        RpcFilterType::DataSize(FenerbahceTracker::SIZE as u64),
+        // schema_version is the first byte of the account
+        RpcFilterType::Memcmp(Memcmp::new_raw_bytes(0, vec![FenerbahceTracker::SCHEMA_VERSION])),
+    ]
+}
+
+/// Compute budget instructions requesting `units` compute units at `price` micro-lamports per unit
+///
+/// Place them ahead of heavier instructions such as PlayUntilTitle, see `with_compute_budget_prefix`.
+#[cfg(feature = "client-rpc")]
+pub fn with_compute_budget(units: u32, price: u64) -> Vec<Instruction> {
+    vec![
+        ComputeBudgetInstruction::set_compute_unit_limit(units),
+        ComputeBudgetInstruction::set_compute_unit_price(price),
+    ]
+}
+
+/// Prepend the `with_compute_budget` instructions to `instructions`
+#[cfg(feature = "client-rpc")]
+pub fn with_compute_budget_prefix(units: u32, price: u64, instructions: &[Instruction]) -> Vec<Instruction> {
+    let mut all = with_compute_budget(units, price);
+    all.extend_from_slice(instructions);
+    all
+}
+
+/// Play every remaining season, calling `cb` with the updated tracker and the season just played
+///
+/// The tracker must already be initialized. Returns the final tracker state.
+#[cfg(feature = "client-rpc")]
+#[allow(clippy::result_large_err)]
+pub fn run_all_with<F: FnMut(&FenerbahceTracker, &SeasonData)>(
+    rpc: &RpcClient,
+    program_id: &Pubkey,
+    payer: &Keypair,
+    cb: F,
+) -> Result<FenerbahceTracker, ClientError> {
+    let tracker_account = get_tracker_address(program_id);
+    let fetch_tracker = || -> Result<FenerbahceTracker, ClientError> {
+        let data = rpc.get_account_data(&tracker_account)?;
+        FenerbahceTracker::unpack(&data).map_err(|e| ClientErrorKind::Custom(e.to_string()).into())
+    };
+
+    let play = |instruction| {
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&payer.pubkey()),
+            &[payer],
+            rpc.get_latest_blockhash()?,
+        );
+        rpc.send_and_confirm_transaction(&transaction)?;
+        fetch_tracker()
+    };
+
+    run_all_over(program_id, fetch_tracker()?, play, cb).map_err(|e| match e {
+        RunAllError::Transport(e) => e,
+        RunAllError::NoPlayedSeason => ClientErrorKind::Custom("Tracker has no played season".to_string()).into(),
+    })
+}
+
+/// Why `run_all_over` stopped before the schedule was complete
+#[derive(Debug, PartialEq)]
+pub enum RunAllError<E> {
+    /// `play` failed
+    Transport(E),
+    /// The tracker came back from a play without a last played season
+    NoPlayedSeason,
+}
+
+/// The loop behind `run_all_with`, for any way of reaching the program
+///
+/// Starting from `tracker`, sends one PlaySeason instruction to `play` per remaining season.
+/// `play` submits it, waits for it to land and returns the tracker read back afterwards;
+/// `cb` then gets that tracker and the season just played. Returns the final tracker state.
+pub fn run_all_over<E, F: FnMut(&FenerbahceTracker, &SeasonData)>(
+    program_id: &Pubkey,
+    mut tracker: FenerbahceTracker,
+    mut play: impl FnMut(Instruction) -> Result<FenerbahceTracker, E>,
+    mut cb: F,
+) -> Result<FenerbahceTracker, RunAllError<E>> {
+    let tracker_account = get_tracker_address(program_id);
+    while !tracker.is_season_complete() {
+        tracker = play(play_season(program_id, &tracker_account)).map_err(RunAllError::Transport)?;
+        let season = tracker.last_played_season().ok_or(RunAllError::NoPlayedSeason)?;
+        cb(&tracker, season);
+    }
+
+    Ok(tracker)
+}
+
+/// Lamports to airdrop so an account holding `balance` reaches `min_lamports`, `None` if it already does
+pub fn airdrop_shortfall(balance: u64, min_lamports: u64) -> Option<u64> {
+    min_lamports.checked_sub(balance).filter(|&shortfall| shortfall > 0)
+}
+
+/// Airdrop whatever `pubkey` is short of `min_lamports` and wait for it to confirm
+///
+/// Returns the airdrop signature, or `None` without touching the faucet if the balance
+/// already covers `min_lamports`.
+#[cfg(feature = "client-rpc")]
+#[allow(clippy::result_large_err)]
+pub fn ensure_funded(
+    rpc: &RpcClient,
+    pubkey: &Pubkey,
+    min_lamports: u64,
+) -> Result<Option<Signature>, ClientError> {
+    let Some(shortfall) = airdrop_shortfall(rpc.get_balance(pubkey)?, min_lamports) else {
+        return Ok(None);
+    };
+
+    let signature = rpc.request_airdrop(pubkey, shortfall)?;
+    rpc.poll_for_signature(&signature)?;
+    Ok(Some(signature))
+}
+
+/// Send and confirm `tx`, retrying transient RPC failures with exponential backoff
+///
+/// Waits `base_delay`, then twice that, and so on between attempts. Errors the transaction
+/// itself produced (a failed preflight or program error) won't change on retry, so they're
+/// returned straight away.
+#[cfg(feature = "client-rpc")]
+#[allow(clippy::result_large_err)]
+pub fn submit_with_retry(
+    rpc: &RpcClient,
+    tx: &Transaction,
+    max_attempts: u32,
+    base_delay: Duration,
+) -> Result<Signature, ClientError> {
+    retry_with_backoff(max_attempts, base_delay, || rpc.send_and_confirm_transaction(tx))
+}
+
+#[cfg(feature = "client-rpc")]
+#[allow(clippy::result_large_err)]
+fn retry_with_backoff<T>(
+    max_attempts: u32,
+    base_delay: Duration,
+    mut op: impl FnMut() -> Result<T, ClientError>,
+) -> Result<T, ClientError> {
+    let mut delay = base_delay;
+    let mut attempt = 1;
+    loop {
+        match op() {
+            Ok(value) => return Ok(value),
+            Err(err) if err.get_transaction_error().is_some() || attempt >= max_attempts => {
+                return Err(err)
+            }
+            Err(_) => {
+                std::thread::sleep(delay);
+                delay = delay.saturating_mul(2);
+                attempt += 1;
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -69,7 +616,65 @@ mod tests {
         assert!(!instruction.accounts[2].is_signer); // System program should not be signer
         
         // Check instruction data
-        assert_eq!(instruction.data, vec![0]);
+        assert_eq!(instruction.data, borsh::to_vec(&FenerbahceInstruction::InitializeTracker { scoring_mode: ScoringMode::TrophyMode }).unwrap());
+    }
+
+    #[test]
+    fn test_initialize_tracker_with_mode_instruction() {
+        let program_id = Pubkey::new_unique();
+        let tracker_account = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+
+        let instruction = initialize_tracker_with_mode(
+            &program_id,
+            &tracker_account,
+            &payer,
+            ScoringMode::GloryMode,
+        );
+
+        assert_eq!(instruction.accounts.len(), 3);
+        assert!(instruction.accounts[1].is_signer);
+        assert_eq!(instruction.data, borsh::to_vec(&FenerbahceInstruction::InitializeTracker { scoring_mode: ScoringMode::GloryMode }).unwrap());
+    }
+
+    #[test]
+    fn test_initialize_if_needed_instruction() {
+        let program_id = Pubkey::new_unique();
+        let tracker_account = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+
+        let instruction = initialize_if_needed(&program_id, &tracker_account, &payer);
+
+        // Same accounts as InitializeTracker, different discriminator
+        let initialize = initialize_tracker(&program_id, &tracker_account, &payer);
+        assert_eq!(instruction.accounts, initialize.accounts);
+        assert_eq!(instruction.data, borsh::to_vec(&FenerbahceInstruction::InitializeIfNeeded { scoring_mode: ScoringMode::TrophyMode }).unwrap());
+    }
+
+    #[test]
+    fn test_initialize_and_play_instruction() {
+        let program_id = Pubkey::new_unique();
+        let tracker_account = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+
+        let instruction = initialize_and_play(&program_id, &tracker_account, &payer);
+
+        let initialize = initialize_tracker(&program_id, &tracker_account, &payer);
+        assert_eq!(instruction.accounts, initialize.accounts);
+        assert_eq!(instruction.data, borsh::to_vec(&FenerbahceInstruction::InitializeAndPlay { scoring_mode: ScoringMode::TrophyMode }).unwrap());
+    }
+
+    #[test]
+    fn test_migrate_tracker_instruction() {
+        let program_id = Pubkey::new_unique();
+        let tracker_account = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+
+        let instruction = migrate_tracker(&program_id, &tracker_account, &payer);
+
+        let initialize = initialize_tracker(&program_id, &tracker_account, &payer);
+        assert_eq!(instruction.accounts, initialize.accounts);
+        assert_eq!(instruction.data, borsh::to_vec(&FenerbahceInstruction::MigrateTracker).unwrap());
     }
 
     #[test]
@@ -83,7 +688,305 @@ mod tests {
         assert_eq!(instruction.accounts.len(), 1);
         assert_eq!(instruction.accounts[0].pubkey, tracker_account);
         assert!(!instruction.accounts[0].is_signer);
-        assert_eq!(instruction.data, vec![1]);
+        assert_eq!(instruction.data, borsh::to_vec(&FenerbahceInstruction::PlaySeason { overflow_policy: OverflowPolicy::Checked }).unwrap());
+    }
+
+    #[test]
+    fn test_play_season_logged_instruction() {
+        let program_id = Pubkey::new_unique();
+        let tracker_account = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+
+        let instruction = play_season_logged(&program_id, &tracker_account, &payer);
+
+        assert_eq!(instruction.accounts.len(), 4);
+        assert_eq!(instruction.accounts[1].pubkey, get_season_log_address(&program_id));
+        assert!(instruction.accounts[1].is_writable);
+        assert!(instruction.accounts[2].is_signer);
+        assert_eq!(instruction.data, borsh::to_vec(&FenerbahceInstruction::PlaySeasonLogged).unwrap());
+    }
+
+    #[test]
+    fn test_simulate_play() {
+        let fresh = FenerbahceTracker::new();
+        let next = simulate_play(&fresh);
+
+        assert_eq!(next.current_season, 2011);
+        assert_eq!(next.total_trophies, 18);
+        assert_eq!(next.seasons_played, 1);
+
+        // Nothing changes once the schedule is over or while paused
+        let complete = FenerbahceTracker::replayed_to(FenerbahceTracker::ENDING_SEASON + 1).unwrap();
+        assert_eq!(simulate_play(&complete), complete);
+        let paused = FenerbahceTracker { paused: true, ..FenerbahceTracker::new() };
+        assert_eq!(simulate_play(&paused), paused);
+    }
+
+    #[test]
+    fn test_get_season_instruction() {
+        let program_id = Pubkey::new_unique();
+
+        let instruction = get_season(&program_id, 2013);
+
+        assert!(instruction.accounts.is_empty());
+        assert_eq!(instruction.data, vec![16, 0xdd, 0x07]);
+        assert_eq!(instruction.data, borsh::to_vec(&FenerbahceInstruction::GetSeason { year: 2013 }).unwrap());
+    }
+
+    #[test]
+    fn test_state_hash_instruction() {
+        let program_id = Pubkey::new_unique();
+        let tracker_account = Pubkey::new_unique();
+
+        let instruction = state_hash(&program_id, &tracker_account);
+
+        assert_eq!(instruction.accounts, vec![AccountMeta::new_readonly(tracker_account, false)]);
+        assert_eq!(instruction.data, vec![17]);
+        assert_eq!(instruction.data, borsh::to_vec(&FenerbahceInstruction::StateHash).unwrap());
+    }
+
+    #[test]
+    fn test_status_instruction() {
+        let program_id = Pubkey::new_unique();
+        let tracker_account = Pubkey::new_unique();
+
+        let instruction = status(&program_id, &tracker_account);
+
+        assert_eq!(instruction.accounts, vec![AccountMeta::new_readonly(tracker_account, false)]);
+        assert_eq!(instruction.data, vec![18]);
+        assert_eq!(instruction.data, borsh::to_vec(&FenerbahceInstruction::Status).unwrap());
+    }
+
+    #[test]
+    fn test_decode_status() {
+        let mut fields = vec![
+            STATUS_EVENT.to_vec(),
+            4u16.to_le_bytes().to_vec(),
+            2021u16.to_le_bytes().to_vec(),
+            19u64.to_le_bytes().to_vec(),
+            vec![2],
+            vec![0],
+        ];
+        assert_eq!(
+            decode_status(&fields),
+            Some(TrackerStatus {
+                remaining_seasons: 4,
+                current_season: 2021,
+                total_trophies: 19,
+                next_position: Some(2),
+                next_champion: false,
+            })
+        );
+
+        fields[4] = vec![0];
+        assert_eq!(decode_status(&fields).unwrap().next_position, None);
+
+        // Wrong tag, missing field, or a short field
+        assert_eq!(decode_status(&[b"get_pda".to_vec()]), None);
+        assert_eq!(decode_status(&fields[..5]), None);
+        fields[1] = vec![4];
+        assert_eq!(decode_status(&fields), None);
+    }
+
+    #[test]
+    fn test_decode_season_log() {
+        let data = [0xda, 0x07, 1, 1, 0xdb, 0x07, 2, 0, 0xff];
+
+        assert_eq!(
+            decode_season_log(&data),
+            vec![
+                SeasonLogRecord { season: 2010, position: 1, champion: true },
+                SeasonLogRecord { season: 2011, position: 2, champion: false },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_play_season_with_policy_instruction() {
+        let program_id = Pubkey::new_unique();
+        let tracker_account = Pubkey::new_unique();
+
+        let instruction =
+            play_season_with_policy(&program_id, &tracker_account, OverflowPolicy::Saturating);
+
+        assert_eq!(instruction.accounts, play_season(&program_id, &tracker_account).accounts);
+        assert_eq!(instruction.data, borsh::to_vec(&FenerbahceInstruction::PlaySeason { overflow_policy: OverflowPolicy::Saturating }).unwrap());
+    }
+
+    #[test]
+    fn test_propose_authority_instruction() {
+        let program_id = Pubkey::new_unique();
+        let tracker_account = Pubkey::new_unique();
+        let current_authority = Pubkey::new_unique();
+        let new_authority = Pubkey::new_unique();
+
+        let instruction = propose_authority(&program_id, &tracker_account, &current_authority, &new_authority);
+
+        assert_eq!(instruction.accounts.len(), 2);
+        assert_eq!(instruction.accounts[0].pubkey, tracker_account);
+        assert!(instruction.accounts[0].is_writable);
+        assert_eq!(instruction.accounts[1].pubkey, current_authority);
+        assert!(instruction.accounts[1].is_signer);
+        assert_eq!(instruction.data[0], 2);
+        assert_eq!(&instruction.data[1..], new_authority.as_ref());
+        assert_eq!(instruction.data, borsh::to_vec(&FenerbahceInstruction::ProposeAuthority { new_authority }).unwrap());
+    }
+
+    #[test]
+    fn test_accept_authority_instruction() {
+        let program_id = Pubkey::new_unique();
+        let tracker_account = Pubkey::new_unique();
+        let pending_authority = Pubkey::new_unique();
+
+        let instruction = accept_authority(&program_id, &tracker_account, &pending_authority);
+
+        assert_eq!(instruction.accounts.len(), 2);
+        assert!(instruction.accounts[0].is_writable);
+        assert_eq!(instruction.accounts[1].pubkey, pending_authority);
+        assert!(instruction.accounts[1].is_signer);
+        assert_eq!(instruction.data, borsh::to_vec(&FenerbahceInstruction::AcceptAuthority).unwrap());
+    }
+
+    #[test]
+    fn test_set_reward_program_instruction() {
+        let program_id = Pubkey::new_unique();
+        let tracker_account = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let reward_program = Some(Pubkey::new_unique());
+
+        let instruction = set_reward_program(&program_id, &tracker_account, &authority, reward_program);
+
+        assert_eq!(instruction.accounts.len(), 2);
+        assert_eq!(instruction.accounts[0].pubkey, tracker_account);
+        assert!(instruction.accounts[0].is_writable);
+        assert_eq!(instruction.accounts[1].pubkey, authority);
+        assert!(instruction.accounts[1].is_signer);
+        assert_eq!(instruction.data, borsh::to_vec(&FenerbahceInstruction::SetRewardProgram { reward_program }).unwrap());
+    }
+
+    #[test]
+    fn test_pause_unpause_instructions() {
+        let program_id = Pubkey::new_unique();
+        let tracker_account = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+
+        let pause_ix = pause(&program_id, &tracker_account, &authority);
+        let unpause_ix = unpause(&program_id, &tracker_account, &authority);
+
+        for instruction in [&pause_ix, &unpause_ix] {
+            assert_eq!(instruction.program_id, program_id);
+            assert_eq!(instruction.accounts.len(), 2);
+            assert_eq!(instruction.accounts[0].pubkey, tracker_account);
+            assert!(instruction.accounts[0].is_writable);
+            assert_eq!(instruction.accounts[1].pubkey, authority);
+            assert!(instruction.accounts[1].is_signer);
+        }
+        assert_eq!(pause_ix.data, borsh::to_vec(&FenerbahceInstruction::Pause).unwrap());
+        assert_eq!(unpause_ix.data, borsh::to_vec(&FenerbahceInstruction::Unpause).unwrap());
+    }
+
+    #[test]
+    fn test_get_version_instruction() {
+        let program_id = Pubkey::new_unique();
+
+        let instruction = get_version(&program_id);
+
+        assert_eq!(instruction.program_id, program_id);
+        assert!(instruction.accounts.is_empty());
+        assert_eq!(instruction.data, borsh::to_vec(&FenerbahceInstruction::GetVersion).unwrap());
+    }
+
+    #[test]
+    fn test_get_pda_instruction() {
+        let program_id = Pubkey::new_unique();
+
+        let instruction = get_pda(&program_id);
+
+        assert_eq!(instruction.program_id, program_id);
+        assert!(instruction.accounts.is_empty());
+        assert_eq!(instruction.data, borsh::to_vec(&FenerbahceInstruction::GetPda).unwrap());
+    }
+
+    #[test]
+    fn test_peek_next_season_instruction() {
+        let program_id = Pubkey::new_unique();
+        let tracker_account = Pubkey::new_unique();
+
+        let instruction = peek_next_season(&program_id, &tracker_account);
+
+        assert_eq!(instruction.program_id, program_id);
+        assert_eq!(instruction.accounts.len(), 1);
+        assert_eq!(instruction.accounts[0].pubkey, tracker_account);
+        assert!(!instruction.accounts[0].is_signer);
+        assert!(!instruction.accounts[0].is_writable); // Peeking must never need write access
+        assert_eq!(instruction.data, borsh::to_vec(&FenerbahceInstruction::PeekNextSeason).unwrap());
+    }
+
+    #[test]
+    fn test_verify_integrity_instruction() {
+        let program_id = Pubkey::new_unique();
+        let tracker_account = Pubkey::new_unique();
+
+        let instruction = verify_integrity(&program_id, &tracker_account);
+
+        assert_eq!(instruction.program_id, program_id);
+        assert_eq!(instruction.accounts.len(), 1);
+        assert_eq!(instruction.accounts[0].pubkey, tracker_account);
+        assert!(!instruction.accounts[0].is_signer);
+        assert!(!instruction.accounts[0].is_writable);
+        assert_eq!(instruction.data, borsh::to_vec(&FenerbahceInstruction::VerifyIntegrity).unwrap());
+    }
+
+    #[test]
+    fn test_snapshot_instruction() {
+        let program_id = Pubkey::new_unique();
+        let tracker_account = Pubkey::new_unique();
+        let snapshot_account = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+
+        let instruction = snapshot(&program_id, &tracker_account, &snapshot_account, &authority);
+
+        assert_eq!(instruction.program_id, program_id);
+        assert_eq!(instruction.accounts.len(), 3);
+        assert_eq!(instruction.accounts[0].pubkey, tracker_account);
+        assert!(!instruction.accounts[0].is_writable);
+        assert_eq!(instruction.accounts[1].pubkey, snapshot_account);
+        assert!(instruction.accounts[1].is_writable);
+        assert!(!instruction.accounts[1].is_signer);
+        assert_eq!(instruction.accounts[2], AccountMeta::new_readonly(authority, true));
+        assert_eq!(instruction.data, borsh::to_vec(&FenerbahceInstruction::Snapshot).unwrap());
+    }
+
+    #[test]
+    fn test_decode_events() {
+        let event = TrophyEvent { season: 2013, champion: true, total_trophies: 19 };
+        let log_datas = vec![
+            b"peek_next_season".to_vec(),
+            event.encode(),
+            vec![1, 2, 3], // Another program's event
+        ];
+
+        let events = decode_events(&log_datas);
+
+        assert_eq!(events, vec![event]);
+        assert_eq!(events[0].season, 2013);
+        assert_eq!(events[0].total_trophies, 19);
+        assert!(decode_events(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_play_until_title_instruction() {
+        let program_id = Pubkey::new_unique();
+        let tracker_account = Pubkey::new_unique();
+
+        let instruction = play_until_title(&program_id, &tracker_account);
+
+        assert_eq!(instruction.program_id, program_id);
+        assert_eq!(instruction.accounts.len(), 1);
+        assert_eq!(instruction.accounts[0].pubkey, tracker_account);
+        assert!(instruction.accounts[0].is_writable);
+        assert!(!instruction.accounts[0].is_signer);
+        assert_eq!(instruction.data, borsh::to_vec(&FenerbahceInstruction::PlayUntilTitle).unwrap());
     }
 
     #[test]
@@ -94,4 +997,141 @@ mod tests {
         // Should return the same address each time for the same program
         assert_eq!(tracker_address, get_tracker_address(&program_id));
     }
+
+    #[test]
+    fn test_airdrop_shortfall() {
+        assert_eq!(airdrop_shortfall(0, 1_000), Some(1_000));
+        assert_eq!(airdrop_shortfall(400, 1_000), Some(600));
+        assert_eq!(airdrop_shortfall(1_000, 1_000), None);
+        assert_eq!(airdrop_shortfall(5_000, 1_000), None);
+    }
+
+    #[cfg(feature = "client-rpc")]
+    #[test]
+    fn test_ensure_funded() {
+        // The mock RPC reports a balance of 50 lamports
+        let rpc = RpcClient::new_mock("succeeds".to_string());
+        let pubkey = Pubkey::new_unique();
+
+        assert_eq!(ensure_funded(&rpc, &pubkey, 50).unwrap(), None);
+        assert!(ensure_funded(&rpc, &pubkey, 1_000).unwrap().is_some());
+    }
+
+    #[cfg(feature = "client-rpc")]
+    #[test]
+    fn test_with_compute_budget() {
+        let budget = with_compute_budget(400_000, 1_000);
+
+        assert_eq!(budget.len(), 2);
+        for instruction in &budget {
+            assert_eq!(instruction.program_id, solana_sdk::compute_budget::id());
+            assert!(instruction.accounts.is_empty());
+        }
+        assert_eq!(budget[0], ComputeBudgetInstruction::set_compute_unit_limit(400_000));
+        assert_eq!(budget[1], ComputeBudgetInstruction::set_compute_unit_price(1_000));
+
+        let program_id = Pubkey::new_unique();
+        let play_ix = play_until_title(&program_id, &get_tracker_address(&program_id));
+        let instructions = with_compute_budget_prefix(400_000, 1_000, std::slice::from_ref(&play_ix));
+        assert_eq!(instructions[..2], budget[..]);
+        assert_eq!(instructions[2], play_ix);
+    }
+
+    #[cfg(feature = "client-rpc")]
+    #[test]
+    fn test_program_account_filters() {
+        let filters = program_account_filters();
+
+        assert_eq!(filters.len(), 2);
+        assert_eq!(filters[0], RpcFilterType::DataSize(FenerbahceTracker::SIZE as u64));
+        assert_eq!(
+            filters[1],
+            RpcFilterType::Memcmp(Memcmp::new_raw_bytes(0, vec![FenerbahceTracker::SCHEMA_VERSION]))
+        );
+    }
+
+    #[cfg(feature = "client-rpc")]
+    #[test]
+    #[allow(clippy::result_large_err)]
+    fn test_retry_with_backoff_recovers_from_transient_errors() {
+        let mut calls = 0;
+
+        let result = retry_with_backoff(5, Duration::from_millis(1), || {
+            calls += 1;
+            if calls <= 2 {
+                Err(ClientErrorKind::Io(std::io::Error::other("connection reset")).into())
+            } else {
+                Ok(calls)
+            }
+        });
+
+        assert_eq!(result.unwrap(), 3);
+    }
+
+    #[cfg(feature = "client-rpc")]
+    #[test]
+    #[allow(clippy::result_large_err)]
+    fn test_retry_with_backoff_stops_on_permanent_errors() {
+        use solana_sdk::transaction::TransactionError;
+
+        let mut calls = 0;
+        let result: Result<(), _> = retry_with_backoff(5, Duration::from_millis(1), || {
+            calls += 1;
+            Err(ClientErrorKind::TransactionError(TransactionError::AccountNotFound).into())
+        });
+        assert!(result.is_err());
+        assert_eq!(calls, 1);
+
+        // Transient errors give up after max_attempts
+        let mut calls = 0;
+        let result: Result<(), _> = retry_with_backoff(3, Duration::from_millis(1), || {
+            calls += 1;
+            Err(ClientErrorKind::Custom("timed out".to_string()).into())
+        });
+        assert!(result.is_err());
+        assert_eq!(calls, 3);
+    }
+
+    #[cfg(feature = "client-rpc")]
+    #[test]
+    fn test_submit_with_retry() {
+        let rpc = RpcClient::new_mock("succeeds".to_string());
+        let payer = Keypair::new();
+        let tx = Transaction::new_signed_with_payer(
+            &[play_season(&Pubkey::new_unique(), &Pubkey::new_unique())],
+            Some(&payer.pubkey()),
+            &[&payer],
+            rpc.get_latest_blockhash().unwrap(),
+        );
+
+        assert_eq!(submit_with_retry(&rpc, &tx, 3, Duration::from_millis(1)).unwrap(), tx.signatures[0]);
+    }
+
+    #[test]
+    fn test_run_all_over_stops_on_errors() {
+        let program_id = Pubkey::new_unique();
+        let mut calls = 0;
+
+        let failed = run_all_over(&program_id, FenerbahceTracker::new(), |_| Err("offline"), |_, _| calls += 1);
+        assert_eq!(failed, Err(RunAllError::Transport("offline")));
+
+        // A play that doesn't move the tracker leaves no season to report
+        let unmoved = |_| Ok::<_, ()>(FenerbahceTracker::new());
+        let stuck = run_all_over(&program_id, FenerbahceTracker::new(), unmoved, |_, _| calls += 1);
+        assert_eq!(stuck, Err(RunAllError::NoPlayedSeason));
+        assert_eq!(calls, 0);
+    }
+
+    #[cfg(feature = "client-rpc")]
+    #[test]
+    fn test_run_all_with_missing_tracker() {
+        // The mock RPC reports every account as missing, so nothing gets played
+        let rpc = RpcClient::new_mock("succeeds".to_string());
+        let mut calls = 0;
+
+        let result = run_all_with(&rpc, &Pubkey::new_unique(), &Keypair::new(), |_, _| calls += 1);
+
+        assert!(result.is_err());
+        assert_eq!(calls, 0);
+    }
 }
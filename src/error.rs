@@ -0,0 +1,122 @@
+use solana_program::program_error::ProgramError;
+use thiserror::Error;
+
+/// Errors that may be returned by the Fenerbahçe tracker program
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FenerbahceError {
+    /// Tracker account was written with a schema version this program doesn't understand
+    #[error("Unsupported tracker schema version {0}, run MigrateTracker to upgrade the account")]
+    UnsupportedSchemaVersion(u8),
+
+    /// No season data exists for the requested year
+    #[error("No season data for {0}")]
+    SeasonOutOfRange(u16),
+
+    /// Incrementing `seasons_played` would overflow
+    #[error("Seasons played counter overflow")]
+    SeasonCounterOverflow,
+
+    /// Stored tracker fields don't match a replay of the season history
+    #[error("Tracker state doesn't match the replayed season history")]
+    IntegrityViolation,
+
+    /// Tracker state could not be written into the account data
+    #[error("Failed to serialize tracker into account data")]
+    SerializationFailed,
+
+    /// Signer is not the authority allowed to perform this action
+    #[error("Signer is not the tracker authority")]
+    UnauthorizedAuthority,
+
+    /// AcceptAuthority was called with no proposed authority
+    #[error("No pending authority to accept")]
+    NoPendingAuthority,
+
+    /// Season string isn't a `YYYY-YYYY` pair of consecutive years
+    #[error("Malformed season string, expected e.g. \"2013-2014\"")]
+    BadSeasonString,
+
+    /// Tracker account was passed read-only to an instruction that writes it
+    #[error("Tracker account must be writable")]
+    AccountNotWritable,
+
+    /// The authority has paused the tracker
+    #[error("Tracker is paused")]
+    TrackerPaused,
+
+    /// Adding a season's trophy or glory points would overflow
+    #[error("Trophy or glory score overflow")]
+    ScoreOverflow,
+
+    /// League position outside `1..=Position::LEAGUE_SIZE`
+    #[error("Invalid league position {0}")]
+    InvalidPosition(u8),
+
+    /// The payer can't cover the rent for a grown tracker account
+    #[error("Payer has insufficient funds to keep the tracker rent-exempt")]
+    InsufficientRentFunds,
+
+    /// Tracker account address isn't this program's tracker PDA
+    #[error("Tracker account is not the tracker PDA for this program")]
+    WrongTrackerPda,
+
+    /// The created tracker account doesn't have the size the program allocated for it
+    #[error("Created tracker account size doesn't match the tracker layout")]
+    AllocationSizeMismatch,
+
+    /// A season row disagrees with the season being played, or with its own title flag
+    #[error("Season data for {0} is inconsistent")]
+    InconsistentSeasonData(u16),
+
+    /// The tracker's `current_season` is a year the season table has no row for
+    #[error("No season data for the tracker's current season {0}")]
+    NoDataForCurrentSeason(u16),
+
+    /// A custom tracker was asked to start on a season without data or below the initial trophies
+    #[error("Invalid tracker initialization parameters")]
+    InvalidInitParams,
+
+    /// PlaySeason was given a reward program other than the one registered with SetRewardProgram
+    #[error("Reward program is not registered with the tracker")]
+    UnregisteredRewardProgram,
+}
+
+impl FenerbahceError {
+    /// Custom error code reported on-chain as `ProgramError::Custom(code)`
+    pub fn code(&self) -> u32 {
+        match self {
+            FenerbahceError::UnsupportedSchemaVersion(_) => 0,
+            FenerbahceError::SeasonOutOfRange(_) => 1,
+            FenerbahceError::SeasonCounterOverflow => 2,
+            FenerbahceError::IntegrityViolation => 3,
+            FenerbahceError::SerializationFailed => 4,
+            FenerbahceError::UnauthorizedAuthority => 5,
+            FenerbahceError::NoPendingAuthority => 6,
+            FenerbahceError::BadSeasonString => 7,
+            FenerbahceError::AccountNotWritable => 8,
+            FenerbahceError::TrackerPaused => 9,
+            FenerbahceError::ScoreOverflow => 10,
+            FenerbahceError::InvalidPosition(_) => 11,
+            FenerbahceError::InsufficientRentFunds => 12,
+            FenerbahceError::WrongTrackerPda => 13,
+            // 14 is held for a non-canonical bump error, once an instruction takes a bump
+            FenerbahceError::AllocationSizeMismatch => 15,
+            FenerbahceError::InconsistentSeasonData(_) => 16,
+            FenerbahceError::NoDataForCurrentSeason(_) => 17,
+            FenerbahceError::InvalidInitParams => 18,
+            FenerbahceError::UnregisteredRewardProgram => 19,
+        }
+    }
+}
+
+impl From<std::num::ParseIntError> for FenerbahceError {
+    fn from(_: std::num::ParseIntError) -> Self {
+        FenerbahceError::BadSeasonString
+    }
+}
+
+impl From<FenerbahceError> for ProgramError {
+    fn from(e: FenerbahceError) -> Self {
+        ProgramError::Custom(e.code())
+    }
+}
@@ -0,0 +1,73 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::state::{FenerbahceTracker, SeasonData};
+
+/// `prefix` followed by the borsh-encoded `payload`, the framing of events and hook instructions
+pub(crate) fn encode_prefixed(prefix: &[u8; 8], payload: &impl BorshSerialize) -> Vec<u8> {
+    let mut bytes = prefix.to_vec();
+    // Writing fixed-size fields into a Vec can't fail
+    payload.serialize(&mut bytes).unwrap();
+    bytes
+}
+
+/// Inverse of `encode_prefixed`, `None` unless `bytes` is `prefix` followed by one whole payload
+pub(crate) fn decode_prefixed<T: BorshDeserialize>(prefix: &[u8; 8], bytes: &[u8]) -> Option<T> {
+    let payload = bytes.strip_prefix(prefix.as_slice())?;
+    T::try_from_slice(payload).ok()
+}
+
+/// Emitted once for every season PlaySeason or PlayUntilTitle plays
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TrophyEvent {
+    pub season: u16,         // Starting year of the season just played
+    pub champion: bool,      // Whether the season brought a title
+    pub total_trophies: u64, // Trophy count after the season
+}
+
+impl TrophyEvent {
+    /// Leading bytes of an encoded TrophyEvent
+    pub const PREFIX: &'static [u8; 8] = b"fbtrophy";
+
+    /// Describe `season_data` as just played by `tracker`
+    pub fn new(season_data: &SeasonData, tracker: &FenerbahceTracker) -> Self {
+        Self {
+            season: season_data.season,
+            champion: season_data.champion,
+            total_trophies: tracker.total_trophies,
+        }
+    }
+
+    /// The log-data field for this event, `PREFIX` followed by the borsh payload
+    pub fn encode(&self) -> Vec<u8> {
+        encode_prefixed(Self::PREFIX, self)
+    }
+
+    /// Parse a log-data field, returning `None` if it isn't a well-formed TrophyEvent
+    pub fn decode(bytes: &[u8]) -> Option<Self> {
+        decode_prefixed(Self::PREFIX, bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trophy_event_round_trip() {
+        let event = TrophyEvent { season: 2013, champion: true, total_trophies: 19 };
+        let bytes = event.encode();
+
+        assert_eq!(&bytes[..8], TrophyEvent::PREFIX);
+        assert_eq!(bytes.len(), 8 + 2 + 1 + 8);
+        assert_eq!(TrophyEvent::decode(&bytes), Some(event));
+    }
+
+    #[test]
+    fn test_trophy_event_decode_rejects_other_data() {
+        let bytes = TrophyEvent { season: 2010, champion: true, total_trophies: 18 }.encode();
+
+        assert_eq!(TrophyEvent::decode(b"peek_next_season"), None);
+        assert_eq!(TrophyEvent::decode(&bytes[..bytes.len() - 1]), None);
+        assert_eq!(TrophyEvent::decode(&[]), None);
+    }
+}
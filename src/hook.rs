@@ -0,0 +1,77 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+
+use crate::event::{decode_prefixed, encode_prefixed};
+
+/// Payload of the instruction sent to the reward program on a title season
+///
+/// Only the program registered with SetRewardProgram is invoked, with the tracker PDA signing.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TitleWon {
+    pub season: u16,         // Starting year of the title season
+    pub total_trophies: u64, // Trophy count including the new title
+}
+
+impl TitleWon {
+    /// Leading bytes of an encoded TitleWon instruction
+    pub const DISCRIMINATOR: &'static [u8; 8] = b"fbtitlew";
+
+    /// The instruction data, `DISCRIMINATOR` followed by the borsh payload
+    pub fn encode(&self) -> Vec<u8> {
+        encode_prefixed(Self::DISCRIMINATOR, self)
+    }
+
+    /// Parse instruction data, returning `None` if it isn't a well-formed TitleWon
+    pub fn decode(bytes: &[u8]) -> Option<Self> {
+        decode_prefixed(Self::DISCRIMINATOR, bytes)
+    }
+
+    /// The CPI to `reward_program`, with the signing tracker ahead of the forwarded accounts
+    pub fn instruction(
+        &self,
+        reward_program: &Pubkey,
+        tracker_account: &Pubkey,
+        forwarded_accounts: impl IntoIterator<Item = AccountMeta>,
+    ) -> Instruction {
+        let mut accounts = vec![AccountMeta::new_readonly(*tracker_account, true)];
+        accounts.extend(forwarded_accounts);
+
+        Instruction {
+            program_id: *reward_program,
+            accounts,
+            data: self.encode(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_title_won_round_trip() {
+        let title = TitleWon { season: 2013, total_trophies: 19 };
+        let bytes = title.encode();
+
+        assert_eq!(&bytes[..8], TitleWon::DISCRIMINATOR);
+        assert_eq!(bytes.len(), 8 + 2 + 8);
+        assert_eq!(TitleWon::decode(&bytes), Some(title));
+        assert_eq!(TitleWon::decode(&bytes[1..]), None);
+    }
+
+    #[test]
+    fn test_title_won_instruction_signs_with_tracker() {
+        let reward_program = Pubkey::new_unique();
+        let tracker = Pubkey::new_unique();
+        let mint = AccountMeta::new(Pubkey::new_unique(), false);
+
+        let ix = TitleWon { season: 2010, total_trophies: 18 }
+            .instruction(&reward_program, &tracker, [mint.clone()]);
+
+        assert_eq!(ix.program_id, reward_program);
+        assert_eq!(ix.accounts, vec![AccountMeta::new_readonly(tracker, true), mint]);
+    }
+}
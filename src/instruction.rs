@@ -1,36 +1,240 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{program_error::ProgramError, pubkey::Pubkey};
 
-#[derive(BorshSerialize, BorshDeserialize, Debug)]
+use crate::state::{OverflowPolicy, ScoringMode};
+
+// Borsh writes each variant's discriminant as its first byte, the same byte `unpack`
+// matches on, so skipped numbers stay skipped on the wire.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
+#[borsh(use_discriminant = true)]
+#[repr(u8)]
 pub enum FenerbahceInstruction {
     /// Initialize Fenerbahçe tracker starting from 2010-2011 season
+    ///
+    /// The scoring mode byte is optional and defaults to `TrophyMode` when omitted.
     /// 
     /// Accounts expected by this instruction:
     /// 0. `[writable]` Fenerbahçe tracker PDA account to be initialized
     /// 1. `[writable, signer]` Payer account
     /// 2. `[]` System program
-    InitializeTracker, // variant 0
+    InitializeTracker { scoring_mode: ScoringMode }, // variant 0
     
     /// Play a season and update trophy count if Fenerbahçe won
+    ///
+    /// The overflow policy byte is optional and defaults to `Checked` when omitted.
+    ///
+    /// On a title season the optional reward program is invoked with a `TitleWon`
+    /// instruction, signed by the tracker PDA. It must be the program registered with
+    /// SetRewardProgram, otherwise PlaySeason fails with `UnregisteredRewardProgram`.
     /// 
     /// Accounts expected by this instruction:
     /// 0. `[writable]` Fenerbahçe tracker PDA account
-    PlaySeason, // variant 1
+    /// 1. `[]` Reward program (optional, executable), must equal `tracker.reward_program`
+    /// 2. .. Any accounts the reward program needs (optional), forwarded to its CPI as given,
+    ///    after the signing tracker PDA
+    PlaySeason { overflow_policy: OverflowPolicy }, // variant 1
+
+    /// Propose a new authority, which only takes effect once it calls AcceptAuthority
+    ///
+    /// Accounts expected by this instruction:
+    /// 0. `[writable]` Fenerbahçe tracker PDA account
+    /// 1. `[signer]` Current authority
+    ProposeAuthority { new_authority: Pubkey }, // variant 2
+
+    /// Promote the pending authority to be the tracker authority
+    ///
+    /// Accounts expected by this instruction:
+    /// 0. `[writable]` Fenerbahçe tracker PDA account
+    /// 1. `[signer]` Pending authority
+    AcceptAuthority, // variant 3
+
+    /// Block PlaySeason until the tracker is unpaused
+    ///
+    /// Accounts expected by this instruction:
+    /// 0. `[writable]` Fenerbahçe tracker PDA account
+    /// 1. `[signer]` Tracker authority
+    Pause, // variant 4
+
+    /// Allow PlaySeason again after a Pause
+    ///
+    /// Accounts expected by this instruction:
+    /// 0. `[writable]` Fenerbahçe tracker PDA account
+    /// 1. `[signer]` Tracker authority
+    Unpause, // variant 5
+
+    /// Report the program build version
+    ///
+    /// Emits `[VERSION_EVENT, version (UTF-8)]` via `sol_log_data`.
+    ///
+    /// No accounts expected by this instruction.
+    GetVersion, // variant 6
+
+    /// Upgrade a tracker still in the original 11-byte layout to the current schema
+    ///
+    /// The account is grown to `FenerbahceTracker::SIZE`, with the payer covering any extra
+    /// rent. Anyone can migrate, so the payer doesn't become the authority: it stays the
+    /// default `Pubkey`. A tracker already on the current schema is left as-is.
+    ///
+    /// Nobody can sign as the default `Pubkey`, so a migrated tracker has no usable authority.
+    /// This is intended: the legacy layout never recorded one, and handing it to whoever pays
+    /// would let anyone take it. Pause, Unpause, SetRewardProgram, Snapshot and
+    /// ProposeAuthority stay locked for good, while the permissionless instructions keep
+    /// working.
+    ///
+    /// Accounts expected by this instruction:
+    /// 0. `[writable]` Fenerbahçe tracker PDA account
+    /// 1. `[writable, signer]` Payer account
+    /// 2. `[]` System program
+    MigrateTracker, // variant 7
+
+    /// Preview what playing the next season would do without changing state
+    ///
+    /// Emits `[PEEK_EVENT, season (u16 LE), champion (u8), trophies after season (u64 LE)]`
+    /// via `sol_log_data`.
+    ///
+    /// Accounts expected by this instruction:
+    /// 0. `[]` Fenerbahçe tracker PDA account
+    PeekNextSeason, // variant 8
+
+    /// Replay the season history and check it matches the stored tracker fields
+    ///
+    /// Emits `[INTEGRITY_EVENT, passed (u8)]` via `sol_log_data` and fails with
+    /// `IntegrityViolation` if any field disagrees.
+    ///
+    /// Accounts expected by this instruction:
+    /// 0. `[]` Fenerbahçe tracker PDA account
+    VerifyIntegrity, // variant 9
+
+    /// Copy the tracker's account data into a snapshot account, prefixed with the current slot
+    ///
+    /// The snapshot account must already exist, be owned by this program, hold at least
+    /// `16 + tracker data length` bytes and be either all zeros or an earlier snapshot.
+    /// Layout: `[SNAPSHOT_DISCRIMINATOR, slot (u64 LE), tracker account data]`.
+    ///
+    /// Accounts expected by this instruction:
+    /// 0. `[]` Fenerbahçe tracker PDA account
+    /// 1. `[writable]` Snapshot account
+    /// 2. `[signer]` Tracker authority
+    Snapshot, // variant 10
+
+    /// Like InitializeTracker, but succeeds as a no-op if the tracker already exists
+    ///
+    /// An existing account must be a tracker owned by this program. The scoring mode byte
+    /// is optional and only applies when the tracker is created.
+    ///
+    /// Accounts expected by this instruction:
+    /// 0. `[writable]` Fenerbahçe tracker PDA account
+    /// 1. `[writable, signer]` Payer account
+    /// 2. `[]` System program
+    InitializeIfNeeded { scoring_mode: ScoringMode }, // variant 11
+
+    /// Play seasons until the next title is credited or the schedule ends
+    ///
+    /// Emits `[PLAY_UNTIL_TITLE_EVENT, seasons played (u16 LE), title won (u8)]` via
+    /// `sol_log_data`.
+    ///
+    /// Accounts expected by this instruction:
+    /// 0. `[writable]` Fenerbahçe tracker PDA account
+    PlayUntilTitle, // variant 12
+
+    /// Report the tracker PDA address and bump for this program
+    ///
+    /// Emits `[PDA_EVENT, tracker PDA (32 bytes), bump (u8)]` via `sol_log_data`.
+    ///
+    /// No accounts expected by this instruction.
+    GetPda, // variant 13
+
+    /// InitializeTracker followed by the first PlaySeason, in a single instruction
+    ///
+    /// The scoring mode byte is optional and defaults to `TrophyMode` when omitted.
+    ///
+    /// Accounts expected by this instruction:
+    /// 0. `[writable]` Fenerbahçe tracker PDA account to be initialized
+    /// 1. `[writable, signer]` Payer account
+    /// 2. `[]` System program
+    InitializeAndPlay { scoring_mode: ScoringMode }, // variant 14
+
+    // Variant 15 is reserved for PatchPosition, correcting one slot of a stored position
+    // history. The tracker doesn't store per-season positions (they're always read from
+    // `SeasonData`), so there is nothing to patch until it does.
+
+    /// Report the stored data for one season, for CPI callers that don't link this crate
+    ///
+    /// Emits `[GET_SEASON_EVENT, year (u16 LE), position (u8), champion (u8), points (u16 LE),
+    /// description]` via `sol_log_data`, with the description cut to at most
+    /// `SEASON_DESCRIPTION_MAX_LEN` bytes. Unknown years fail with `SeasonOutOfRange`.
+    ///
+    /// No accounts expected by this instruction.
+    GetSeason { year: u16 } = 16,
+
+    /// Report a digest of the tracker state, for cheap change detection off-chain
+    ///
+    /// Emits `[STATE_HASH_EVENT, hash (32 bytes)]` via `sol_log_data`, where the hash is
+    /// `FenerbahceTracker::state_hash`. The tracker isn't written, so legacy accounts work too.
+    ///
+    /// Accounts expected by this instruction:
+    /// 0. `[]` Fenerbahçe tracker PDA account
+    StateHash = 17,
+
+    /// Report how far the tracker has got and what the next season holds
+    ///
+    /// Emits `[STATUS_EVENT, remaining seasons (u16 LE), current season (u16 LE), total
+    /// trophies (u64 LE), next position (u8), next champion (u8)]` via `sol_log_data`. The
+    /// next position is 0 once the schedule is complete. `client::decode_status` parses it.
+    ///
+    /// Accounts expected by this instruction:
+    /// 0. `[]` Fenerbahçe tracker PDA account
+    Status = 18,
+
+    /// PlaySeason that also appends a `SeasonLogRecord` to the season log PDA
+    ///
+    /// The log account is created on first use and grown by one record per season, with the
+    /// payer covering the extra rent. Nothing is appended once all seasons are complete.
+    ///
+    /// Accounts expected by this instruction:
+    /// 0. `[writable]` Fenerbahçe tracker PDA account
+    /// 1. `[writable]` Season log PDA account
+    /// 2. `[writable, signer]` Payer account
+    /// 3. `[]` System program
+    PlaySeasonLogged = 19,
+
+    /// Register the only program PlaySeason will notify of titles, or clear it with `None`
+    ///
+    /// The tracker PDA signs that notification, so PlaySeason refuses any other reward program.
+    ///
+    /// Accounts expected by this instruction:
+    /// 0. `[writable]` Fenerbahçe tracker PDA account
+    /// 1. `[signer]` Tracker authority
+    SetRewardProgram { reward_program: Option<Pubkey> } = 20,
 }
 
 impl FenerbahceInstruction {
+    /// Borsh-encoded instruction data, which `unpack` reads back into the same instruction
+    pub fn pack(&self) -> Vec<u8> {
+        // Writing into a Vec can't fail
+        borsh::to_vec(self).unwrap()
+    }
+
     /// Unpacks a byte buffer into a FenerbahceInstruction
+    ///
+    /// The layout is borsh's, exactly as `pack` writes it: the variant's discriminant byte
+    /// followed by its fields in declaration order, with nothing left over. The one
+    /// exception is the optional argument byte of the initialize instructions and
+    /// PlaySeason, which clients that predate it leave off.
     pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
-        let (&variant, _rest) = input
-            .split_first()
-            .ok_or(ProgramError::InvalidInstructionData)?;
-
-        // Match instruction type
-        match variant {
-            0 => Ok(FenerbahceInstruction::InitializeTracker),
-            1 => Ok(FenerbahceInstruction::PlaySeason),
-            _ => Err(ProgramError::InvalidInstructionData),
+        // A bare discriminator keeps the original trophy-only, checked behavior
+        let bare = match input {
+            [0] => Some(FenerbahceInstruction::InitializeTracker { scoring_mode: ScoringMode::default() }),
+            [1] => Some(FenerbahceInstruction::PlaySeason { overflow_policy: OverflowPolicy::default() }),
+            [11] => Some(FenerbahceInstruction::InitializeIfNeeded { scoring_mode: ScoringMode::default() }),
+            [14] => Some(FenerbahceInstruction::InitializeAndPlay { scoring_mode: ScoringMode::default() }),
+            _ => None,
+        };
+        if let Some(instruction) = bare {
+            return Ok(instruction);
         }
+
+        Self::try_from_slice(input).map_err(|_| ProgramError::InvalidInstructionData)
     }
 }
 
@@ -42,6 +246,14 @@ pub fn find_tracker_pda(program_id: &Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[FB_TRACKER_SEED], program_id)
 }
 
+/// Seed for the season log PDA PlaySeasonLogged appends to
+pub const FB_SEASON_LOG_SEED: &[u8] = b"fenerbahce_season_log";
+
+/// Find the season log PDA address
+pub fn find_season_log_pda(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[FB_SEASON_LOG_SEED], program_id)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -53,9 +265,28 @@ mod tests {
         let instruction = FenerbahceInstruction::unpack(&instruction_data).unwrap();
         
         match instruction {
-            FenerbahceInstruction::InitializeTracker => {},
+            FenerbahceInstruction::InitializeTracker { scoring_mode } => {
+                assert_eq!(scoring_mode, ScoringMode::TrophyMode);
+            },
+            _ => panic!("Expected InitializeTracker instruction"),
+        }
+    }
+
+    #[test]
+    fn test_unpack_initialize_tracker_with_mode() {
+        let instruction_data = vec![0, 1]; // Variant 0, GloryMode
+
+        let instruction = FenerbahceInstruction::unpack(&instruction_data).unwrap();
+
+        match instruction {
+            FenerbahceInstruction::InitializeTracker { scoring_mode } => {
+                assert_eq!(scoring_mode, ScoringMode::GloryMode);
+            },
             _ => panic!("Expected InitializeTracker instruction"),
         }
+
+        // Unknown scoring modes are rejected
+        assert!(FenerbahceInstruction::unpack(&[0, 7]).is_err());
     }
 
     #[test]
@@ -65,9 +296,320 @@ mod tests {
         let instruction = FenerbahceInstruction::unpack(&instruction_data).unwrap();
         
         match instruction {
-            FenerbahceInstruction::PlaySeason => {},
+            FenerbahceInstruction::PlaySeason { overflow_policy } => {
+                assert_eq!(overflow_policy, OverflowPolicy::Checked);
+            },
             _ => panic!("Expected PlaySeason instruction"),
         }
+
+        match FenerbahceInstruction::unpack(&[1, 1]).unwrap() {
+            FenerbahceInstruction::PlaySeason { overflow_policy } => {
+                assert_eq!(overflow_policy, OverflowPolicy::Saturating);
+            },
+            _ => panic!("Expected PlaySeason instruction"),
+        }
+
+        // Unknown policies are rejected
+        assert!(FenerbahceInstruction::unpack(&[1, 2]).is_err());
+    }
+
+    #[test]
+    fn test_unpack_propose_authority() {
+        let new_authority = Pubkey::new_unique();
+        let mut instruction_data = vec![2]; // Variant 2
+        instruction_data.extend_from_slice(new_authority.as_ref());
+
+        let instruction = FenerbahceInstruction::unpack(&instruction_data).unwrap();
+
+        match instruction {
+            FenerbahceInstruction::ProposeAuthority { new_authority: key } => {
+                assert_eq!(key, new_authority);
+            },
+            _ => panic!("Expected ProposeAuthority instruction"),
+        }
+
+        // The new authority key must be exactly 32 bytes
+        assert!(FenerbahceInstruction::unpack(&[2, 1, 2, 3]).is_err());
+    }
+
+    #[test]
+    fn test_unpack_accept_authority() {
+        let instruction_data = vec![3]; // Variant 3
+
+        let instruction = FenerbahceInstruction::unpack(&instruction_data).unwrap();
+
+        match instruction {
+            FenerbahceInstruction::AcceptAuthority => {},
+            _ => panic!("Expected AcceptAuthority instruction"),
+        }
+    }
+
+    #[test]
+    fn test_unpack_pause_unpause() {
+        match FenerbahceInstruction::unpack(&[4]).unwrap() { // Variant 4
+            FenerbahceInstruction::Pause => {},
+            _ => panic!("Expected Pause instruction"),
+        }
+
+        match FenerbahceInstruction::unpack(&[5]).unwrap() { // Variant 5
+            FenerbahceInstruction::Unpause => {},
+            _ => panic!("Expected Unpause instruction"),
+        }
+    }
+
+    #[test]
+    fn test_unpack_get_version() {
+        let instruction_data = vec![6]; // Variant 6
+
+        let instruction = FenerbahceInstruction::unpack(&instruction_data).unwrap();
+
+        match instruction {
+            FenerbahceInstruction::GetVersion => {},
+            _ => panic!("Expected GetVersion instruction"),
+        }
+    }
+
+    #[test]
+    fn test_unpack_migrate_tracker() {
+        let instruction_data = vec![7]; // Variant 7
+
+        let instruction = FenerbahceInstruction::unpack(&instruction_data).unwrap();
+
+        match instruction {
+            FenerbahceInstruction::MigrateTracker => {},
+            _ => panic!("Expected MigrateTracker instruction"),
+        }
+    }
+
+    #[test]
+    fn test_unpack_peek_next_season() {
+        let instruction_data = vec![8]; // Variant 8
+
+        let instruction = FenerbahceInstruction::unpack(&instruction_data).unwrap();
+
+        match instruction {
+            FenerbahceInstruction::PeekNextSeason => {},
+            _ => panic!("Expected PeekNextSeason instruction"),
+        }
+    }
+
+    #[test]
+    fn test_unpack_verify_integrity() {
+        let instruction_data = vec![9]; // Variant 9
+
+        let instruction = FenerbahceInstruction::unpack(&instruction_data).unwrap();
+
+        match instruction {
+            FenerbahceInstruction::VerifyIntegrity => {},
+            _ => panic!("Expected VerifyIntegrity instruction"),
+        }
+    }
+
+    #[test]
+    fn test_unpack_snapshot() {
+        let instruction_data = vec![10]; // Variant 10
+
+        let instruction = FenerbahceInstruction::unpack(&instruction_data).unwrap();
+
+        match instruction {
+            FenerbahceInstruction::Snapshot => {},
+            _ => panic!("Expected Snapshot instruction"),
+        }
+    }
+
+    #[test]
+    fn test_unpack_initialize_if_needed() {
+        let instruction_data = vec![11]; // Variant 11
+
+        let instruction = FenerbahceInstruction::unpack(&instruction_data).unwrap();
+
+        match instruction {
+            FenerbahceInstruction::InitializeIfNeeded { scoring_mode } => {
+                assert_eq!(scoring_mode, ScoringMode::TrophyMode);
+            },
+            _ => panic!("Expected InitializeIfNeeded instruction"),
+        }
+
+        match FenerbahceInstruction::unpack(&[11, 1]).unwrap() {
+            FenerbahceInstruction::InitializeIfNeeded { scoring_mode } => {
+                assert_eq!(scoring_mode, ScoringMode::GloryMode);
+            },
+            _ => panic!("Expected InitializeIfNeeded instruction"),
+        }
+    }
+
+    #[test]
+    fn test_unpack_play_until_title() {
+        let instruction_data = vec![12]; // Variant 12
+
+        let instruction = FenerbahceInstruction::unpack(&instruction_data).unwrap();
+
+        match instruction {
+            FenerbahceInstruction::PlayUntilTitle => {},
+            _ => panic!("Expected PlayUntilTitle instruction"),
+        }
+    }
+
+    #[test]
+    fn test_unpack_get_pda() {
+        let instruction_data = vec![13]; // Variant 13
+
+        let instruction = FenerbahceInstruction::unpack(&instruction_data).unwrap();
+
+        match instruction {
+            FenerbahceInstruction::GetPda => {},
+            _ => panic!("Expected GetPda instruction"),
+        }
+    }
+
+    #[test]
+    fn test_unpack_initialize_and_play() {
+        let instruction_data = vec![14]; // Variant 14
+
+        let instruction = FenerbahceInstruction::unpack(&instruction_data).unwrap();
+
+        match instruction {
+            FenerbahceInstruction::InitializeAndPlay { scoring_mode } => {
+                assert_eq!(scoring_mode, ScoringMode::TrophyMode);
+            },
+            _ => panic!("Expected InitializeAndPlay instruction"),
+        }
+
+        match FenerbahceInstruction::unpack(&[14, 1]).unwrap() {
+            FenerbahceInstruction::InitializeAndPlay { scoring_mode } => {
+                assert_eq!(scoring_mode, ScoringMode::GloryMode);
+            },
+            _ => panic!("Expected InitializeAndPlay instruction"),
+        }
+    }
+
+    #[test]
+    fn test_unpack_play_season_logged() {
+        match FenerbahceInstruction::unpack(&[19]).unwrap() {
+            FenerbahceInstruction::PlaySeasonLogged => {},
+            _ => panic!("Expected PlaySeasonLogged instruction"),
+        }
+
+        // Borsh keeps the explicit discriminant rather than the declaration index
+        assert_eq!(FenerbahceInstruction::PlaySeasonLogged.pack(), vec![19]);
+    }
+
+    #[test]
+    fn test_unpack_set_reward_program() {
+        let reward_program = Pubkey::new_unique();
+        let mut instruction_data = vec![20, 1]; // Variant 20, Some
+        instruction_data.extend_from_slice(reward_program.as_ref());
+
+        match FenerbahceInstruction::unpack(&instruction_data).unwrap() {
+            FenerbahceInstruction::SetRewardProgram { reward_program: key } => {
+                assert_eq!(key, Some(reward_program));
+            },
+            _ => panic!("Expected SetRewardProgram instruction"),
+        }
+
+        match FenerbahceInstruction::unpack(&[20, 0]).unwrap() {
+            FenerbahceInstruction::SetRewardProgram { reward_program: key } => assert_eq!(key, None),
+            _ => panic!("Expected SetRewardProgram instruction"),
+        }
+
+        // The option tag is required
+        assert!(FenerbahceInstruction::unpack(&[20]).is_err());
+    }
+
+    #[test]
+    fn test_unpack_round_trips_every_variant() {
+        let variants = [
+            FenerbahceInstruction::InitializeTracker { scoring_mode: ScoringMode::GloryMode },
+            FenerbahceInstruction::PlaySeason { overflow_policy: OverflowPolicy::Saturating },
+            FenerbahceInstruction::ProposeAuthority { new_authority: Pubkey::new_unique() },
+            FenerbahceInstruction::AcceptAuthority,
+            FenerbahceInstruction::Pause,
+            FenerbahceInstruction::Unpause,
+            FenerbahceInstruction::GetVersion,
+            FenerbahceInstruction::MigrateTracker,
+            FenerbahceInstruction::PeekNextSeason,
+            FenerbahceInstruction::VerifyIntegrity,
+            FenerbahceInstruction::Snapshot,
+            FenerbahceInstruction::InitializeIfNeeded { scoring_mode: ScoringMode::TrophyMode },
+            FenerbahceInstruction::PlayUntilTitle,
+            FenerbahceInstruction::GetPda,
+            FenerbahceInstruction::InitializeAndPlay { scoring_mode: ScoringMode::GloryMode },
+            FenerbahceInstruction::GetSeason { year: 2013 },
+            FenerbahceInstruction::StateHash,
+            FenerbahceInstruction::Status,
+            FenerbahceInstruction::PlaySeasonLogged,
+            FenerbahceInstruction::SetRewardProgram { reward_program: Some(Pubkey::new_unique()) },
+        ];
+
+        for variant in variants {
+            let bytes = borsh::to_vec(&variant).unwrap();
+            assert_eq!(FenerbahceInstruction::unpack(&bytes).unwrap(), variant);
+        }
+    }
+
+    #[test]
+    fn test_unpack_rejects_trailing_bytes() {
+        assert!(FenerbahceInstruction::unpack(&[3, 0]).is_err());
+        assert!(FenerbahceInstruction::unpack(&[0, 1, 0]).is_err());
+        assert!(FenerbahceInstruction::unpack(&[]).is_err());
+        assert!(FenerbahceInstruction::unpack(&[15]).is_err());
+    }
+
+    /// Whether `unpack` accepting `input` as `instruction` is legitimate: the input is the
+    /// canonical encoding, or a bare discriminator standing for the default argument
+    fn is_well_formed(input: &[u8], instruction: &FenerbahceInstruction) -> bool {
+        let canonical = instruction.pack();
+        input == canonical.as_slice() || (input.len() == 1 && canonical == [input[0], 0])
+    }
+
+    #[test]
+    fn test_unpack_random_bytes() {
+        // Fixed-seed xorshift, so a failure reproduces without a fuzzing toolchain
+        let mut state = 0x9E37_79B9_7F4A_7C15u64;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+
+        let mut accepted = 0;
+        for _ in 0..50_000 {
+            let len = (next() % 40) as usize;
+            let mut input: Vec<u8> = (0..len).map(|_| next() as u8).collect();
+            // Aim most inputs at real discriminators so argument parsing gets exercised
+            if let Some(first) = input.first_mut() {
+                if next() % 4 != 0 {
+                    *first %= 21;
+                }
+            }
+
+            if let Ok(instruction) = FenerbahceInstruction::unpack(&input) {
+                assert!(is_well_formed(&input, &instruction), "accepted {:?} as {:?}", input, instruction);
+                accepted += 1;
+            }
+        }
+        assert!(accepted > 0);
+
+        // Every canonical encoding and bare form is accepted
+        for input in [&[0][..], &[1], &[11], &[14], &[16, 0xdd, 0x07], &[2; 33]] {
+            let instruction = FenerbahceInstruction::unpack(input).unwrap();
+            assert!(is_well_formed(input, &instruction));
+        }
+    }
+
+    #[test]
+    fn test_fuzz_seed_corpus_unpacks() {
+        let corpus = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("fuzz/corpus/unpack_instruction");
+        let mut seeds = 0;
+        for entry in std::fs::read_dir(corpus).unwrap() {
+            let path = entry.unwrap().path();
+            let input = std::fs::read(&path).unwrap();
+            let instruction = FenerbahceInstruction::unpack(&input).unwrap_or_else(|_| panic!("{:?}", path));
+            assert!(is_well_formed(&input, &instruction), "{:?}", path);
+            seeds += 1;
+        }
+        assert!(seeds > 0);
     }
 
     #[test]
@@ -1,11 +1,33 @@
+/// Decorative `msg!` logging, compiled out unless the `verbose-logs` feature is enabled
+#[cfg(feature = "verbose-logs")]
+macro_rules! log_verbose {
+    ($($arg:tt)*) => {
+        solana_program::msg!($($arg)*)
+    };
+}
+
+/// Decorative `msg!` logging, compiled out unless the `verbose-logs` feature is enabled
+#[cfg(not(feature = "verbose-logs"))]
+macro_rules! log_verbose {
+    ($($arg:tt)*) => {};
+}
+
 #[cfg(not(feature = "no-entrypoint"))]
 mod entrypoint;
 pub mod client;
+pub mod error;
+pub mod event;
+pub mod hook;
 pub mod instruction;
+pub mod math;
 pub mod processor;
+pub mod sim;
 pub mod state;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
 // Re-export for convenience
+pub use error::FenerbahceError;
 pub use instruction::FenerbahceInstruction;
 pub use processor::Processor;
 pub use state::{FenerbahceTracker, SeasonData};
\ No newline at end of file
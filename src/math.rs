@@ -0,0 +1,42 @@
+use crate::error::FenerbahceError;
+
+/// The season after `season`, failing with `SeasonOutOfRange` past `u16::MAX`
+pub fn inc_season(season: u16) -> Result<u16, FenerbahceError> {
+    season.checked_add(1).ok_or(FenerbahceError::SeasonOutOfRange(season))
+}
+
+/// `count + 1` for a seasons-played style counter, failing with `SeasonCounterOverflow`
+pub fn inc_counter(count: u16) -> Result<u16, FenerbahceError> {
+    count.checked_add(1).ok_or(FenerbahceError::SeasonCounterOverflow)
+}
+
+/// `total` with one more trophy, failing with `ScoreOverflow`
+pub fn add_trophy(total: u64) -> Result<u64, FenerbahceError> {
+    total.checked_add(1).ok_or(FenerbahceError::ScoreOverflow)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inc_season() {
+        assert_eq!(inc_season(2010), Ok(2011));
+        assert_eq!(inc_season(u16::MAX - 1), Ok(u16::MAX));
+        assert_eq!(inc_season(u16::MAX), Err(FenerbahceError::SeasonOutOfRange(u16::MAX)));
+    }
+
+    #[test]
+    fn test_inc_counter() {
+        assert_eq!(inc_counter(0), Ok(1));
+        assert_eq!(inc_counter(u16::MAX - 1), Ok(u16::MAX));
+        assert_eq!(inc_counter(u16::MAX), Err(FenerbahceError::SeasonCounterOverflow));
+    }
+
+    #[test]
+    fn test_add_trophy() {
+        assert_eq!(add_trophy(17), Ok(18));
+        assert_eq!(add_trophy(u64::MAX - 1), Ok(u64::MAX));
+        assert_eq!(add_trophy(u64::MAX), Err(FenerbahceError::ScoreOverflow));
+    }
+}
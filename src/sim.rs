@@ -0,0 +1,87 @@
+use crate::{
+    error::FenerbahceError,
+    state::{FenerbahceTracker, ScoringMode, SeasonData},
+};
+
+/// In-memory tracker that mirrors the program's PlaySeason behavior
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocalTracker {
+    tracker: FenerbahceTracker,
+}
+
+impl LocalTracker {
+    /// Fresh tracker in `TrophyMode`, as after InitializeTracker
+    pub fn new() -> Self {
+        Self::with_mode(ScoringMode::TrophyMode)
+    }
+
+    pub fn with_mode(scoring_mode: ScoringMode) -> Self {
+        Self { tracker: FenerbahceTracker::new_with_mode(scoring_mode) }
+    }
+
+    /// Current tracker state
+    pub fn tracker(&self) -> &FenerbahceTracker {
+        &self.tracker
+    }
+
+    /// Play the next season, returning `None` once every season is complete
+    ///
+    /// Like the processor, a paused tracker is rejected with `TrackerPaused`.
+    pub fn play_season(&mut self) -> Result<Option<&'static SeasonData>, FenerbahceError> {
+        if self.tracker.paused {
+            return Err(FenerbahceError::TrackerPaused);
+        }
+        if self.tracker.is_season_complete() {
+            return Ok(None);
+        }
+        self.tracker.advance_season().map(Some)
+    }
+
+    /// Start over from the initial state, keeping the scoring mode
+    pub fn reset(&mut self) {
+        self.tracker.reset();
+    }
+}
+
+impl Default for LocalTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_local_tracker_full_run() {
+        let mut local = LocalTracker::new();
+        let mut played = Vec::new();
+
+        while let Some(season) = local.play_season().unwrap() {
+            played.push(season.season);
+        }
+
+        assert_eq!(played.len(), SeasonData::SEASONS.len());
+        assert!(local.tracker().is_season_complete());
+        assert_eq!(local.tracker().total_trophies, 19);
+        assert_eq!(
+            Some(local.tracker().clone()),
+            FenerbahceTracker::replayed_to(FenerbahceTracker::ENDING_SEASON + 1)
+        );
+
+        // Playing past the end is a no-op, as on-chain
+        assert!(local.play_season().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_local_tracker_glory_mode_and_reset() {
+        let mut local = LocalTracker::with_mode(ScoringMode::GloryMode);
+        while local.play_season().unwrap().is_some() {}
+        #[cfg(not(feature = "future-seasons"))]
+        assert_eq!(local.tracker().glory_score, 15);
+
+        local.reset();
+        assert_eq!(local.tracker(), &FenerbahceTracker::new_with_mode(ScoringMode::GloryMode));
+    }
+}
@@ -1,170 +1,2241 @@
 use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    hash::{hash, Hash},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+};
+
+use crate::{error::FenerbahceError, math};
+
+/// How a played season is scored, chosen once at initialization
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScoringMode {
+    /// Only league titles are counted
+    #[default]
+    TrophyMode, // variant 0
+    /// Titles are counted and every finish also earns glory points (see `SeasonData::glory_points`)
+    GloryMode, // variant 1
+}
+
+/// What PlaySeason does when a score would exceed its integer range
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverflowPolicy {
+    /// Fail with `ScoreOverflow` and leave the tracker unchanged
+    #[default]
+    Checked, // variant 0
+    /// Cap the score at its maximum value and keep playing
+    Saturating, // variant 1
+}
+
+impl OverflowPolicy {
+    fn add_u64(self, a: u64, b: u64) -> Result<u64, FenerbahceError> {
+        match self {
+            OverflowPolicy::Checked => a.checked_add(b).ok_or(FenerbahceError::ScoreOverflow),
+            OverflowPolicy::Saturating => Ok(a.saturating_add(b)),
+        }
+    }
+
+    fn add_u32(self, a: u32, b: u32) -> Result<u32, FenerbahceError> {
+        match self {
+            OverflowPolicy::Checked => a.checked_add(b).ok_or(FenerbahceError::ScoreOverflow),
+            OverflowPolicy::Saturating => Ok(a.saturating_add(b)),
+        }
+    }
+}
 
 /// Define struct representing Fenerbahçe's championship tracker
-#[derive(BorshSerialize, BorshDeserialize, Debug)]
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, PartialEq, Eq)]
 pub struct FenerbahceTracker {
+    pub schema_version: u8,     // Account layout version, always the leading byte
     pub total_trophies: u64,    // Total league championships
-    pub current_season: u16,    // Current season year (e.g., 2010 for 2010-2011 season)
-    pub seasons_played: u8,     // Number of seasons completed since 2010
+    pub current_season: Season, // Current season year (e.g., 2010 for 2010-2011 season)
+    pub seasons_played: u16,    // Number of seasons completed since 2010
+    pub scoring_mode: ScoringMode, // Scoring rules selected at initialization
+    pub glory_score: u32,       // Accumulated glory points, only grows in GloryMode
+    pub authority: Pubkey,      // Key allowed to administer the tracker, set to the payer at init
+    pub paused: bool,           // Set by the authority to block PlaySeason during maintenance
+    pub pending_authority: Option<Pubkey>, // Proposed new authority awaiting acceptance
+    pub reward_program: Option<Pubkey>, // Only program PlaySeason will notify of titles, set by the authority
 }
 
 impl FenerbahceTracker {
+    pub const SCHEMA_VERSION: u8 = 6;
     pub const STARTING_SEASON: u16 = 2010;
-    pub const ENDING_SEASON: u16 = 2024;
+    // Derived from the season table so feature-extended schedules move the end automatically
+    pub const ENDING_SEASON: u16 = Self::STARTING_SEASON + SeasonData::SEASONS.len() as u16 - 1;
     pub const INITIAL_TROPHIES: u64 = 17;
+    /// Serialized size with every field at its largest (both optional keys present):
+    /// schema_version + total_trophies + current_season + seasons_played + scoring_mode
+    /// + glory_score + authority + paused + pending_authority + reward_program
+    pub const SIZE: usize = 1 + 8 + 2 + 2 + 1 + 4 + 32 + 1 + (1 + 32) + (1 + 32);
+    /// Size of the original, unversioned layout: total_trophies + current_season + seasons_played (u8)
+    pub const LEGACY_SIZE: usize = 8 + 2 + 1;
     
+    /// Lamports a tracker account of `SIZE` bytes needs to be rent-exempt
+    ///
+    /// Pass `Rent::get()?` on-chain, or a `Rent` fetched from or modelled on the cluster off-chain.
+    pub fn rent_exempt_lamports(rent: &Rent) -> u64 {
+        rent.minimum_balance(Self::SIZE)
+    }
+
+    /// SHA-256 of the borsh-serialized tracker, as logged by StateHash
+    ///
+    /// Covers the serialized fields only, so padding after them in the account doesn't count.
+    pub fn state_hash(&self) -> Hash {
+        // Fixed-size fields and Options can't fail to serialize
+        hash(&borsh::to_vec(self).unwrap())
+    }
+
     pub fn new() -> Self {
+        Self::new_with_mode(ScoringMode::TrophyMode)
+    }
+
+    pub fn new_with_mode(scoring_mode: ScoringMode) -> Self {
         Self {
+            schema_version: Self::SCHEMA_VERSION,
             total_trophies: Self::INITIAL_TROPHIES,
-            current_season: Self::STARTING_SEASON,
+            current_season: Season::new(Self::STARTING_SEASON),
             seasons_played: 0,
+            scoring_mode,
+            glory_score: 0,
+            authority: Pubkey::default(),
+            paused: false,
+            pending_authority: None,
+            reward_program: None,
+        }
+    }
+
+    /// A fresh tracker starting at `start_season` with `initial_trophies` already won
+    ///
+    /// Fails with `InvalidInitParams` unless the season has data and the trophy count is at
+    /// least `INITIAL_TROPHIES`, the count going into 2010-2011.
+    pub fn try_new(start_season: u16, initial_trophies: u64) -> Result<Self, FenerbahceError> {
+        if SeasonData::get_season_data(start_season).is_none() || initial_trophies < Self::INITIAL_TROPHIES {
+            return Err(FenerbahceError::InvalidInitParams);
+        }
+        Ok(Self {
+            total_trophies: initial_trophies,
+            current_season: Season::new(start_season),
+            ..Self::new()
+        })
+    }
+
+    /// Start the schedule over, keeping only the authority, scoring mode and reward program
+    ///
+    /// Rebuilt from `new_with_mode` rather than patched field by field, so every derived
+    /// field (trophies, seasons, glory score, pause and pending authority) is back to its
+    /// initial value, including fields added later.
+    pub fn reset(&mut self) {
+        *self = Self {
+            authority: self.authority,
+            reward_program: self.reward_program,
+            ..Self::new_with_mode(self.scoring_mode)
+        };
+    }
+
+    /// Deserialize from account data, ignoring the unused tail left when
+    /// `pending_authority` or `reward_program` is `None`
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        Ok(Self::deserialize(&mut &data[..])?)
+    }
+    
+    /// Upgrade the original 11-byte layout, which predates `schema_version`, to the current one
+    ///
+    /// Fields the legacy layout lacks get their `new()` defaults.
+    pub fn from_legacy_bytes(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEGACY_SIZE {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        let (trophies, rest) = data.split_at(8);
+        let (season, played) = rest.split_at(2);
+
+        Ok(Self {
+            total_trophies: u64::from_le_bytes(trophies.try_into().unwrap()),
+            current_season: Season::new(u16::from_le_bytes(season.try_into().unwrap())),
+            seasons_played: played[0] as u16,
+            ..Self::new()
+        })
+    }
+
+    /// Deserialize either the current layout or the legacy 11-byte one, told apart by length
+    ///
+    /// Legacy data is upgraded in memory only, see `from_legacy_bytes`.
+    ///
+    /// Only the first `SIZE` bytes are read, so an oversized account's tail is ignored.
+    pub fn from_account_bytes(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() == Self::LEGACY_SIZE {
+            Self::from_legacy_bytes(data)
+        } else {
+            Self::unpack(&data[..data.len().min(Self::SIZE)])
+        }
+    }
+
+    /// Serialize into the account's data, failing cleanly if the account is too small
+    ///
+    /// The unused part of the first `SIZE` bytes is zeroed so no stale optional key is
+    /// left behind; anything past `SIZE` is left untouched.
+    pub fn save_to(&self, account: &AccountInfo) -> ProgramResult {
+        let bytes = borsh::to_vec(self).map_err(|_| FenerbahceError::SerializationFailed)?;
+        let mut data = account.try_borrow_mut_data()?;
+        if data.len() < bytes.len() {
+            return Err(FenerbahceError::SerializationFailed.into());
         }
+        let window = data.len().min(Self::SIZE);
+        data[..bytes.len()].copy_from_slice(&bytes);
+        data[bytes.len()..window].fill(0);
+        Ok(())
     }
     
+    #[cfg(feature = "alloc")]
     pub fn get_season_string(&self) -> String {
-        format!("{}-{}", self.current_season, self.current_season + 1)
+        self.current_season.to_string_label()
     }
     
+    /// Parse a season string like "2013-2014" back into its starting year
+    ///
+    /// Inverse of `get_season_string`. The trailing year is optional but must follow the
+    /// leading one when present.
+    pub fn season_from_string(s: &str) -> Result<u16, FenerbahceError> {
+        let (start, end) = match s.trim().split_once('-') {
+            Some((start, end)) => (start, Some(end)),
+            None => (s.trim(), None),
+        };
+
+        let year: u16 = start.parse()?;
+        if let Some(end) = end {
+            let end_year: u16 = end.parse()?;
+            if year.checked_add(1) != Some(end_year) {
+                return Err(FenerbahceError::BadSeasonString);
+            }
+        }
+
+        if !(Self::STARTING_SEASON..=Self::ENDING_SEASON).contains(&year) {
+            return Err(FenerbahceError::SeasonOutOfRange(year));
+        }
+        Ok(year)
+    }
+
     pub fn is_season_complete(&self) -> bool {
         self.current_season > Self::ENDING_SEASON
     }
+
+    /// Championships won since tracking started in 2010
+    pub fn titles_this_era(&self) -> u64 {
+        self.total_trophies.saturating_sub(Self::INITIAL_TROPHIES)
+    }
+
+    /// Titles won per season played so far, 0.0 before the first season
+    pub fn title_rate_so_far(&self) -> f64 {
+        if self.seasons_played == 0 {
+            return 0.0;
+        }
+        self.titles_this_era() as f64 / self.seasons_played as f64
+    }
+
+    /// Number of seasons still left to play
+    pub fn remaining_seasons(&self) -> u16 {
+        (Self::ENDING_SEASON + 1).saturating_sub(self.current_season.year().max(Self::STARTING_SEASON))
+    }
+
+    /// Number of PlaySeason transactions left before the schedule is complete
+    ///
+    /// Each PlaySeason plays exactly one season, so this is `remaining_seasons`; PlayUntilTitle
+    /// can get there in fewer calls.
+    pub fn calls_to_completion(&self) -> u16 {
+        self.remaining_seasons()
+    }
+
+    /// Share of the schedule played so far, from 0 to 100
+    pub fn progress_percent(&self) -> u8 {
+        let total = SeasonData::SEASONS.len() as u32;
+        let played = total - self.remaining_seasons() as u32;
+        (played * 100 / total) as u8
+    }
+
+    /// Tracker state expected after playing every season from `STARTING_SEASON` up to (not including) `season`
+    ///
+    /// Returns `None` if `season` lies outside the schedule.
+    pub fn replayed_to(season: u16) -> Option<Self> {
+        Self::replayed_to_with_mode(season, ScoringMode::TrophyMode)
+    }
+
+    /// Like `replayed_to`, for a tracker scored with `scoring_mode`
+    ///
+    /// In GloryMode the replay also accumulates the glory points of every season played.
+    pub fn replayed_to_with_mode(season: u16, scoring_mode: ScoringMode) -> Option<Self> {
+        if !(Self::STARTING_SEASON..=Self::ENDING_SEASON + 1).contains(&season) {
+            return None;
+        }
+
+        // A finished schedule needs no scan
+        let titles = if season > Self::ENDING_SEASON {
+            ERA_TITLES
+        } else {
+            SeasonData::SEASONS
+                .iter()
+                .filter(|s| s.season < season && s.champion)
+                .count() as u64
+        };
+
+        let glory_score = match scoring_mode {
+            ScoringMode::GloryMode => SeasonData::SEASONS
+                .iter()
+                .filter(|s| s.season < season)
+                .map(|s| s.glory_points())
+                .sum(),
+            ScoringMode::TrophyMode => 0,
+        };
+
+        Some(Self {
+            total_trophies: Self::INITIAL_TROPHIES + titles,
+            current_season: Season::new(season),
+            seasons_played: season - Self::STARTING_SEASON,
+            glory_score,
+            ..Self::new_with_mode(scoring_mode)
+        })
+    }
+
+    /// Apply the current season's result and move on to the next, the state change behind PlaySeason
+    ///
+    /// Leaves the tracker unchanged on error.
+    pub fn advance_season(&mut self) -> Result<&'static SeasonData, FenerbahceError> {
+        self.advance_season_with(OverflowPolicy::Checked)
+    }
+
+    /// Like `advance_season`, with `policy` deciding how score overflow is handled
+    pub fn advance_season_with(
+        &mut self,
+        policy: OverflowPolicy,
+    ) -> Result<&'static SeasonData, FenerbahceError> {
+        let season_data = SeasonData::try_get(self.current_season.year())?;
+        #[cfg(feature = "strict")]
+        season_data.check_consistent(self.current_season.year())?;
+
+        let total_trophies = policy.add_u64(self.total_trophies, season_data.champion as u64)?;
+        let glory_score = match self.scoring_mode {
+            ScoringMode::GloryMode => policy.add_u32(self.glory_score, season_data.glory_points())?,
+            ScoringMode::TrophyMode => self.glory_score,
+        };
+        let current_season = self.current_season.next()?;
+        let seasons_played = math::inc_counter(self.seasons_played)?;
+
+        self.total_trophies = total_trophies;
+        self.glory_score = glory_score;
+        self.current_season = current_season;
+        self.seasons_played = seasons_played;
+        Ok(season_data)
+    }
+
+    /// Whether a score has reached its maximum, after which Saturating play stops counting
+    pub fn is_score_capped(&self) -> bool {
+        self.total_trophies == u64::MAX || self.glory_score == u32::MAX
+    }
+
+    /// What changed going from `self` to `other`, typically the states before and after a PlaySeason
+    pub fn diff(&self, other: &Self) -> TrackerDiff {
+        let trophy_delta = other.total_trophies as i128 - self.total_trophies as i128;
+        TrackerDiff {
+            trophy_delta,
+            season_delta: other.current_season.year() as i32 - self.current_season.year() as i32,
+            seasons_played_delta: other.seasons_played as i32 - self.seasons_played as i32,
+            glory_delta: other.glory_score as i64 - self.glory_score as i64,
+            title_won: trophy_delta > 0,
+            authority_changed: other.authority != self.authority,
+            paused_changed: other.paused != self.paused,
+        }
+    }
+
+    /// Season data for the most recently played season, or `None` before the first one
+    pub fn last_played_season(&self) -> Option<&'static SeasonData> {
+        if self.seasons_played == 0 {
+            return None;
+        }
+        SeasonData::get_season_data(self.current_season.year().checked_sub(1)?)
+    }
+
+    /// Season data for the season about to be played, or `None` once all seasons are complete
+    pub fn upcoming_season(&self) -> Option<&'static SeasonData> {
+        if self.is_season_complete() {
+            return None;
+        }
+        SeasonData::get_season_data(self.current_season.year())
+    }
+}
+
+impl Default for FenerbahceTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Longest label `format_season_into` can produce, "65535-65536"
+pub const SEASON_STRING_MAX_LEN: usize = 11;
+
+/// Label a season by its starting year, e.g. 2013 becomes "2013-2014"
+#[cfg(feature = "alloc")]
+pub fn format_season(year: u16) -> String {
+    format!("{}-{}", year, year as u32 + 1)
+}
+
+/// Allocation-free `format_season`, writing the label into `buf`
+pub fn format_season_into(year: u16, buf: &mut [u8; SEASON_STRING_MAX_LEN]) -> &str {
+    let mut len = write_digits(year as u32, buf);
+    buf[len] = b'-';
+    len += 1;
+    len += write_digits(year as u32 + 1, &mut buf[len..]);
+    // Only ASCII digits and '-' were written
+    core::str::from_utf8(&buf[..len]).unwrap()
+}
+
+/// Write `value` in decimal at the start of `buf`, returning the number of bytes used
+fn write_digits(mut value: u32, buf: &mut [u8]) -> usize {
+    let mut digits = [0u8; 5];
+    let mut count = 0;
+    loop {
+        digits[count] = b'0' + (value % 10) as u8;
+        count += 1;
+        value /= 10;
+        if value == 0 {
+            break;
+        }
+    }
+    for (i, digit) in digits[..count].iter().rev().enumerate() {
+        buf[i] = *digit;
+    }
+    count
+}
+
+#[cfg(any(test, feature = "test-utils"))]
+impl FenerbahceTracker {
+    /// Start building a tracker from the initial state, for tests
+    pub fn builder() -> TrackerBuilder {
+        TrackerBuilder { tracker: Self::new() }
+    }
+}
+
+/// Chainable constructor for test trackers, validated by `build`
+#[cfg(any(test, feature = "test-utils"))]
+#[derive(Debug, Clone)]
+pub struct TrackerBuilder {
+    tracker: FenerbahceTracker,
+}
+
+#[cfg(any(test, feature = "test-utils"))]
+impl TrackerBuilder {
+    pub fn trophies(mut self, total_trophies: u64) -> Self {
+        self.tracker.total_trophies = total_trophies;
+        self
+    }
+
+    pub fn season(mut self, current_season: u16) -> Self {
+        self.tracker.current_season = Season::new(current_season);
+        self
+    }
+
+    pub fn played(mut self, seasons_played: u16) -> Self {
+        self.tracker.seasons_played = seasons_played;
+        self
+    }
+
+    /// Check that the season is within the schedule and no more seasons were played than have elapsed
+    ///
+    /// A season before the end must also have a row in the table, so a built tracker can always play.
+    pub fn build(self) -> Result<FenerbahceTracker, FenerbahceError> {
+        let tracker = self.tracker;
+        let season = tracker.current_season.year();
+        if !(FenerbahceTracker::STARTING_SEASON..=FenerbahceTracker::ENDING_SEASON + 1).contains(&season) {
+            return Err(FenerbahceError::SeasonOutOfRange(season));
+        }
+        if !tracker.is_season_complete() && SeasonData::get_season_data(season).is_none() {
+            return Err(FenerbahceError::NoDataForCurrentSeason(season));
+        }
+        if tracker.seasons_played > season - FenerbahceTracker::STARTING_SEASON {
+            return Err(FenerbahceError::IntegrityViolation);
+        }
+        Ok(tracker)
+    }
+}
+
+/// Starting year of a season, the tracker's cursor through the schedule
+///
+/// Serializes exactly like the bare `u16` it wraps. The year past `ENDING_SEASON` marks a
+/// finished schedule, so any year can be held; `is_in_range` says whether it's playable.
+/// There's no arithmetic on it: `next` is the only way to advance, and `year` hands out the
+/// bare value for lookups and logging.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Season(u16);
+
+impl Season {
+    pub const fn new(year: u16) -> Self {
+        Self(year)
+    }
+
+    /// The starting year as a bare `u16`, e.g. 2013 for 2013-2014
+    pub const fn year(self) -> u16 {
+        self.0
+    }
+
+    /// The following season, failing with `SeasonOutOfRange` past `u16::MAX`
+    pub fn next(self) -> Result<Self, FenerbahceError> {
+        math::inc_season(self.0).map(Self)
+    }
+
+    /// Whether the season is within the schedule, from `STARTING_SEASON` to `ENDING_SEASON`
+    pub fn is_in_range(self) -> bool {
+        (FenerbahceTracker::STARTING_SEASON..=FenerbahceTracker::ENDING_SEASON).contains(&self.0)
+    }
+
+    /// Label such as "2013-2014", see `format_season`
+    #[cfg(feature = "alloc")]
+    pub fn to_string_label(self) -> String {
+        format_season(self.0)
+    }
+}
+
+/// Same label as `format_season`, written straight to the formatter so `msg!` needs no `alloc`
+impl std::fmt::Display for Season {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.0, self.0 as u32 + 1)
+    }
+}
+
+impl From<Season> for u16 {
+    fn from(season: Season) -> Self {
+        season.0
+    }
+}
+
+impl PartialEq<u16> for Season {
+    fn eq(&self, year: &u16) -> bool {
+        self.0 == *year
+    }
+}
+
+impl PartialOrd<u16> for Season {
+    fn partial_cmp(&self, year: &u16) -> Option<std::cmp::Ordering> {
+        self.0.partial_cmp(year)
+    }
+}
+
+/// Final league position, always within `1..=LEAGUE_SIZE`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Position(u8);
+
+impl Position {
+    /// Most teams the Süper Lig has fielded in a season
+    pub const LEAGUE_SIZE: u8 = 20;
+
+    pub const fn new(value: u8) -> Result<Self, FenerbahceError> {
+        if value == 0 || value > Self::LEAGUE_SIZE {
+            return Err(FenerbahceError::InvalidPosition(value));
+        }
+        Ok(Self(value))
+    }
+}
+
+impl std::ops::Deref for Position {
+    type Target = u8;
+
+    fn deref(&self) -> &u8 {
+        &self.0
+    }
+}
+
+impl From<Position> for u8 {
+    fn from(position: Position) -> Self {
+        position.0
+    }
+}
+
+impl TryFrom<u8> for Position {
+    type Error = FenerbahceError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl std::fmt::Display for Position {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Colour-coding bucket for a season, see `SeasonData::tier`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeasonTier {
+    /// Won the league
+    Champion,
+    /// Finished second within `TITLE_CONTENDER_MARGIN` points of the champion
+    TitleContender,
+    /// Any other top-four finish
+    Europe,
+    /// Fifth or lower
+    Disappointment,
+}
+
+impl std::fmt::Display for SeasonTier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            SeasonTier::Champion => "Champion",
+            SeasonTier::TitleContender => "Title Contender",
+            SeasonTier::Europe => "Europe",
+            SeasonTier::Disappointment => "Disappointment",
+        })
+    }
+}
+
+/// Field-by-field changes between two tracker states, see `FenerbahceTracker::diff`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TrackerDiff {
+    pub trophy_delta: i128,        // Wide enough for any pair of u64 counts
+    pub season_delta: i32,
+    pub seasons_played_delta: i32,
+    pub glory_delta: i64,
+    pub title_won: bool,           // At least one trophy was added
+    pub authority_changed: bool,
+    pub paused_changed: bool,
+}
+
+impl TrackerDiff {
+    /// Whether nothing tracked by the diff changed
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// One entry of the season log PlaySeasonLogged appends to, 4 bytes once serialized
+#[derive(BorshSerialize, BorshDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SeasonLogRecord {
+    pub season: u16,    // Starting year of the season played
+    pub position: u8,   // Final league position
+    pub champion: bool, // Whether the season brought a title
+}
+
+impl SeasonLogRecord {
+    /// Serialized size of a record
+    pub const SIZE: usize = 2 + 1 + 1;
+
+    /// The record for a season just played
+    pub fn from_season(season_data: &SeasonData) -> Self {
+        Self {
+            season: season_data.season,
+            position: *season_data.position,
+            champion: season_data.champion,
+        }
+    }
+}
+
+/// One-shot summary of the whole schedule, see `SeasonData::era_summary`
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct EraSummary {
+    pub total_seasons: u16,
+    pub titles: u16,
+    pub runner_ups: u16,
+    pub best_points: u16,
+    pub worst_position: u8,
+    pub average_points: f64,
+    pub champion_years: Vec<u16>,
+}
+
+/// One `label: value` line per field, with the average to one decimal place
+#[cfg(feature = "alloc")]
+impl std::fmt::Display for EraSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Seasons: {}", self.total_seasons)?;
+        write!(f, "Titles: {}", self.titles)?;
+        for (i, year) in self.champion_years.iter().enumerate() {
+            write!(f, "{}{}", if i == 0 { " (" } else { ", " }, year)?;
+        }
+        if !self.champion_years.is_empty() {
+            f.write_str(")")?;
+        }
+        writeln!(f)?;
+        writeln!(f, "Runner-ups: {}", self.runner_ups)?;
+        writeln!(f, "Best points: {}", self.best_points)?;
+        writeln!(f, "Worst position: {}", self.worst_position)?;
+        write!(f, "Average points: {:.1}", self.average_points)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl EraSummary {
+    /// Compact JSON object with every field, written by hand to keep serde out of the program
+    pub fn to_json(&self) -> String {
+        let years: Vec<String> = self.champion_years.iter().map(|y| y.to_string()).collect();
+        // JSON has no NaN, which an empty schedule's average would be
+        let average = if self.average_points.is_finite() {
+            self.average_points.to_string()
+        } else {
+            String::from("null")
+        };
+
+        format!(
+            "{{\"total_seasons\":{},\"titles\":{},\"runner_ups\":{},\"best_points\":{},\"worst_position\":{},\"average_points\":{},\"champion_years\":[{}]}}",
+            self.total_seasons,
+            self.titles,
+            self.runner_ups,
+            self.best_points,
+            self.worst_position,
+            average,
+            years.join(","),
+        )
+    }
+}
+
+/// Position for the season table, so a bad entry fails the build instead of slipping through
+const fn pos(value: u8) -> Position {
+    match Position::new(value) {
+        Ok(position) => position,
+        Err(_) => panic!("league position out of range"),
+    }
 }
 
 /// Fenerbahçe's league positions from 2010-2011 to 2024-2025
+#[derive(Clone, Copy, Debug)]
 pub struct SeasonData {
     pub season: u16,
-    pub position: u8,
+    pub position: Position,
     pub champion: bool,
     pub points: u16,
+    /// Points the champion finished on (Fenerbahçe's own total in title years)
+    pub champion_points: u16,
+    /// Team that won the league that season
+    pub champion_team: &'static str,
     pub description: &'static str,
 }
 
+/// `SeasonData` with owned strings, for storing or handing off a copy of a season row
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OwnedSeasonData {
+    pub season: u16,
+    pub position: Position,
+    pub champion: bool,
+    pub points: u16,
+    pub champion_points: u16,
+    pub champion_team: String,
+    pub description: String,
+}
+
+#[cfg(feature = "alloc")]
+impl From<&SeasonData> for OwnedSeasonData {
+    fn from(season: &SeasonData) -> Self {
+        Self {
+            season: season.season,
+            position: season.position,
+            champion: season.champion,
+            points: season.points,
+            champion_points: season.champion_points,
+            champion_team: season.champion_team.to_string(),
+            description: season.description.to_string(),
+        }
+    }
+}
+
 impl SeasonData {
-    pub const SEASONS: [SeasonData; 15] = [
-        SeasonData { season: 2010, position: 1, champion: true, points: 82, description: "🏆 CHAMPIONS! Title won under Aykut Kocaman, finished same point with Trabzonspor (82 pts)" },
-        SeasonData { season: 2011, position: 2, champion: false, points: 68, description: "2nd place finish, 9 points behind champion Galatasaray (77 pts)" },
-        SeasonData { season: 2012, position: 2, champion: false, points: 61, description: "2nd place finish, 10 points behind champion Galatasaray (71 pts)" },
-        SeasonData { season: 2013, position: 1, champion: true, points: 74, description: "🏆 CHAMPIONS! Title won under Ersun Yanal, finished 9 points ahead of Galatasaray (65 pts)" },
-        SeasonData { season: 2014, position: 2, champion: false, points: 74, description: "2nd place finish, 3 points behind champion Galatasaray (77 pts)" },
-        SeasonData { season: 2015, position: 2, champion: false, points: 74, description: "2nd place finish, 5 points behind champion Beşiktaş (79 pts)" },
-        SeasonData { season: 2016, position: 3, champion: false, points: 64, description: "3rd place finish, 13 points behind champion Beşiktaş (77 pts)" },
-        SeasonData { season: 2017, position: 2, champion: false, points: 72, description: "2nd place finish, 3 points behind champion Galatasaray (75 pts)" },
-        SeasonData { season: 2018, position: 6, champion: false, points: 46, description: "6th place finish, 23 points behind champion Galatasaray (69 pts)" },
-        SeasonData { season: 2019, position: 7, champion: false, points: 53, description: "7th place finish, 13 points behind champion Başakşehir (66 pts)" },
-        SeasonData { season: 2020, position: 3, champion: false, points: 82, description: "3rd place finish, tied on points with Galatasaray, 2 points behind champion Beşiktaş (84 pts)" },
-        SeasonData { season: 2021, position: 2, champion: false, points: 73, description: "2nd place finish, 8 points behind champion Trabzonspor (81 pts)" },
-        SeasonData { season: 2022, position: 2, champion: false, points: 80, description: "2nd place finish, 5 points behind champion Galatasaray (85 pts)" },
-        SeasonData { season: 2023, position: 2, champion: false, points: 99, description: "2nd place finish despite a record 99 points, 3 points behind champion Galatasaray (102 pts)" },
-        SeasonData { season: 2024, position: 2, champion: false, points: 84, description: "2nd place finish, 11 points behind champion Galatasaray (95 pts)" },
+    /// Full season table, extended with `FUTURE_SEASONS` when the `future-seasons` feature is on
+    #[cfg(not(feature = "future-seasons"))]
+    pub const SEASONS: [SeasonData; 15] = Self::ERA_SEASONS;
+    #[cfg(feature = "future-seasons")]
+    pub const SEASONS: [SeasonData; Self::ERA_SEASONS.len() + Self::FUTURE_SEASONS.len()] =
+        concat_seasons(&Self::ERA_SEASONS, &Self::FUTURE_SEASONS);
+
+    /// Seasons appended after 2024-2025 as they conclude, in chronological order
+    #[cfg(feature = "future-seasons")]
+    pub const FUTURE_SEASONS: [SeasonData; 1] = [
+        // Provisional points, still to be checked against the final Süper Lig table
+        SeasonData { season: 2025, position: pos(2), champion: false, points: 76, champion_points: 83, champion_team: "Galatasaray", description: "2nd place finish, 7 points behind champion Galatasaray (83 pts)" },
+    ];
+
+    const ERA_SEASONS: [SeasonData; 15] = [
+        SeasonData { season: 2010, position: pos(1), champion: true, points: 82, champion_points: 82, champion_team: "Fenerbahçe", description: "🏆 CHAMPIONS! Title won under Aykut Kocaman, finished same point with Trabzonspor (82 pts)" },
+        SeasonData { season: 2011, position: pos(2), champion: false, points: 68, champion_points: 77, champion_team: "Galatasaray", description: "2nd place finish, 9 points behind champion Galatasaray (77 pts)" },
+        SeasonData { season: 2012, position: pos(2), champion: false, points: 61, champion_points: 71, champion_team: "Galatasaray", description: "2nd place finish, 10 points behind champion Galatasaray (71 pts)" },
+        SeasonData { season: 2013, position: pos(1), champion: true, points: 74, champion_points: 74, champion_team: "Fenerbahçe", description: "🏆 CHAMPIONS! Title won under Ersun Yanal, finished 9 points ahead of Galatasaray (65 pts)" },
+        SeasonData { season: 2014, position: pos(2), champion: false, points: 74, champion_points: 77, champion_team: "Galatasaray", description: "2nd place finish, 3 points behind champion Galatasaray (77 pts)" },
+        SeasonData { season: 2015, position: pos(2), champion: false, points: 74, champion_points: 79, champion_team: "Beşiktaş", description: "2nd place finish, 5 points behind champion Beşiktaş (79 pts)" },
+        SeasonData { season: 2016, position: pos(3), champion: false, points: 64, champion_points: 77, champion_team: "Beşiktaş", description: "3rd place finish, 13 points behind champion Beşiktaş (77 pts)" },
+        SeasonData { season: 2017, position: pos(2), champion: false, points: 72, champion_points: 75, champion_team: "Galatasaray", description: "2nd place finish, 3 points behind champion Galatasaray (75 pts)" },
+        SeasonData { season: 2018, position: pos(6), champion: false, points: 46, champion_points: 69, champion_team: "Galatasaray", description: "6th place finish, 23 points behind champion Galatasaray (69 pts)" },
+        SeasonData { season: 2019, position: pos(7), champion: false, points: 53, champion_points: 66, champion_team: "Başakşehir", description: "7th place finish, 13 points behind champion Başakşehir (66 pts)" },
+        SeasonData { season: 2020, position: pos(3), champion: false, points: 82, champion_points: 84, champion_team: "Beşiktaş", description: "3rd place finish, tied on points with Galatasaray, 2 points behind champion Beşiktaş (84 pts)" },
+        SeasonData { season: 2021, position: pos(2), champion: false, points: 73, champion_points: 81, champion_team: "Trabzonspor", description: "2nd place finish, 8 points behind champion Trabzonspor (81 pts)" },
+        SeasonData { season: 2022, position: pos(2), champion: false, points: 80, champion_points: 85, champion_team: "Galatasaray", description: "2nd place finish, 5 points behind champion Galatasaray (85 pts)" },
+        SeasonData { season: 2023, position: pos(2), champion: false, points: 99, champion_points: 102, champion_team: "Galatasaray", description: "2nd place finish despite a record 99 points, 3 points behind champion Galatasaray (102 pts)" },
+        SeasonData { season: 2024, position: pos(2), champion: false, points: 84, champion_points: 95, champion_team: "Galatasaray", description: "2nd place finish, 11 points behind champion Galatasaray (95 pts)" },
     ];
     
     pub fn get_season_data(season_year: u16) -> Option<&'static SeasonData> {
         Self::SEASONS.iter().find(|s| s.season == season_year)
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Glory points earned in `GloryMode`: 3 for a title, 1 for runner-up, nothing otherwise
+    pub fn glory_points(&self) -> u32 {
+        match *self.position {
+            1 => 3,
+            2 => 1,
+            _ => 0,
+        }
+    }
 
-    #[test]
-    fn test_fenerbahce_tracker_serialization() {
-        let original = FenerbahceTracker {
-            total_trophies: 19,
-            current_season: 2013,
-            seasons_played: 3,
-        };
-        
-        // Serialize using borsh directly
-        let serialized = borsh::to_vec(&original).unwrap();
-        
-        // Deserialize
-        let deserialized = FenerbahceTracker::try_from_slice(&serialized).unwrap();
-        
-        assert_eq!(original.total_trophies, deserialized.total_trophies);
-        assert_eq!(original.current_season, deserialized.current_season);
-        assert_eq!(original.seasons_played, deserialized.seasons_played);
+    /// Like `get_season_data`, but reports a missing year as `SeasonOutOfRange`
+    pub fn try_get(season_year: u16) -> Result<&'static SeasonData, FenerbahceError> {
+        Self::get_season_data(season_year).ok_or(FenerbahceError::SeasonOutOfRange(season_year))
     }
 
-    #[test]
-    fn test_fenerbahce_tracker_size() {
-        let tracker = FenerbahceTracker::new();
-        let serialized = borsh::to_vec(&tracker).unwrap();
-        
-        // Should be 8 bytes (u64) + 2 bytes (u16) + 1 byte (u8) = 11 bytes
-        assert_eq!(serialized.len(), 11);
+    /// Seasons in which Fenerbahçe won the league
+    #[cfg(feature = "alloc")]
+    pub fn championship_years() -> Vec<u16> {
+        Self::SEASONS.iter().filter(|s| s.champion).map(|s| s.season).collect()
     }
 
-    #[test]
-    fn test_fenerbahce_tracker_new() {
-        let tracker = FenerbahceTracker::new();
-        
-        assert_eq!(tracker.total_trophies, FenerbahceTracker::INITIAL_TROPHIES);
-        assert_eq!(tracker.current_season, FenerbahceTracker::STARTING_SEASON);
-        assert_eq!(tracker.seasons_played, 0);
+    /// Points Fenerbahçe finished behind the champion, 0 in title years
+    pub fn points_behind(&self) -> u16 {
+        self.champion_points.saturating_sub(self.points)
     }
 
-    #[test]
-    fn test_fenerbahce_tracker_season_string() {
-        let tracker = FenerbahceTracker {
-            total_trophies: 17,
-            current_season: 2010,
-            seasons_played: 0,
-        };
-        
-        assert_eq!(tracker.get_season_string(), "2010-2011");
-        
-        let tracker2 = FenerbahceTracker {
-            total_trophies: 19,
-            current_season: 2013,
-            seasons_played: 3,
-        };
-        
-        assert_eq!(tracker2.get_season_string(), "2013-2014");
+    /// Sum of the title margins across every season Fenerbahçe didn't win
+    pub fn total_points_behind() -> u32 {
+        Self::SEASONS
+            .iter()
+            .filter(|s| !s.champion)
+            .map(|s| s.points_behind() as u32)
+            .sum()
     }
 
-    #[test]
-    fn test_fenerbahce_tracker_season_completion() {
-        let mut tracker = FenerbahceTracker::new();
-        assert!(!tracker.is_season_complete());
-        
-        tracker.current_season = 2024;
-        assert!(!tracker.is_season_complete());
-        
-        tracker.current_season = 2025;
-        assert!(tracker.is_season_complete());
+    /// Largest gap to the champion that still counts as a title race
+    pub const TITLE_CONTENDER_MARGIN: u16 = 5;
+
+    /// Bucket the season by finish, with close second places set apart from other top-four years
+    pub fn tier(&self) -> SeasonTier {
+        if self.champion {
+            SeasonTier::Champion
+        } else if *self.position == 2 && self.points_behind() <= Self::TITLE_CONTENDER_MARGIN {
+            SeasonTier::TitleContender
+        } else if *self.position <= 4 {
+            SeasonTier::Europe
+        } else {
+            SeasonTier::Disappointment
+        }
+    }
+
+    /// The non-title season with the most points, the earliest one on a tie
+    pub fn heartbreak_season() -> Option<&'static SeasonData> {
+        Self::SEASONS
+            .iter()
+            .rev()
+            .filter(|s| !s.champion)
+            .max_by_key(|s| s.points)
+    }
+
+    /// Points that didn't bring a title, 0 in title years
+    pub fn points_wasted(&self) -> u16 {
+        if self.champion {
+            0
+        } else {
+            self.points
+        }
+    }
+
+    /// Headline numbers for the whole schedule in a single struct, for dashboards
+    #[cfg(feature = "alloc")]
+    pub fn era_summary() -> EraSummary {
+        let seasons = Self::SEASONS.iter();
+        let total_points: u32 = seasons.clone().map(|s| s.points as u32).sum();
+
+        EraSummary {
+            total_seasons: Self::SEASONS.len() as u16,
+            titles: seasons.clone().filter(|s| s.champion).count() as u16,
+            runner_ups: seasons.clone().filter(|s| *s.position == 2).count() as u16,
+            best_points: seasons.clone().map(|s| s.points).max().unwrap_or(0),
+            worst_position: seasons.clone().map(|s| *s.position).max().unwrap_or(0),
+            average_points: total_points as f64 / Self::SEASONS.len() as f64,
+            champion_years: Self::championship_years(),
+        }
+    }
+
+    /// Number of seasons finished in each league position, indexed by position (index 0 unused)
+    ///
+    /// Finishes below 7th aren't counted.
+    pub fn position_counts() -> [u8; 8] {
+        let mut counts = [0u8; 8];
+        for s in Self::SEASONS.iter() {
+            if let Some(count) = counts.get_mut(*s.position as usize) {
+                *count += 1;
+            }
+        }
+        counts
+    }
+
+    /// Longest run of consecutive runner-up finishes as `(start year, end year, length)`
+    ///
+    /// The earliest run wins ties, and `(0, 0, 0)` means there were no runner-up finishes.
+    pub fn longest_runner_up_streak() -> (u16, u16, u8) {
+        Self::longest_runner_up_streak_in(&Self::SEASONS)
+    }
+
+    fn longest_runner_up_streak_in(seasons: &[SeasonData]) -> (u16, u16, u8) {
+        Self::longest_run_in(seasons, |s| *s.position == 2)
+    }
+
+    /// Seasons in which `team` won the league
+    #[cfg(feature = "alloc")]
+    pub fn titles_lost_to(team: &str) -> Vec<u16> {
+        Self::SEASONS.iter().filter(|s| s.champion_team == team).map(|s| s.season).collect()
+    }
+
+    /// Titles per season across the whole schedule
+    pub fn title_rate() -> f64 {
+        ERA_TITLES as f64 / Self::SEASONS.len() as f64
+    }
+
+    /// Population variance of the league points across the whole schedule
+    pub fn points_variance() -> f64 {
+        let seasons = Self::SEASONS.len() as f64;
+        let mean = Self::SEASONS.iter().map(|s| s.points as f64).sum::<f64>() / seasons;
+        Self::SEASONS.iter().map(|s| (s.points as f64 - mean).powi(2)).sum::<f64>() / seasons
+    }
+
+    /// Standard deviation of the league points, the square root of `points_variance`
+    pub fn points_std_dev() -> f64 {
+        Self::points_variance().sqrt()
+    }
+
+    /// Copy of this row with owned strings
+    ///
+    /// Named apart from `ToOwned::to_owned`, which `Clone` already provides.
+    #[cfg(feature = "alloc")]
+    pub fn to_owned_data(&self) -> OwnedSeasonData {
+        OwnedSeasonData::from(self)
+    }
+
+    /// Seasons for which `pred` holds, in schedule order
+    #[cfg(feature = "alloc")]
+    pub fn filter(pred: impl Fn(&SeasonData) -> bool) -> Vec<&'static SeasonData> {
+        Self::SEASONS.iter().filter(|s| pred(s)).collect()
+    }
+
+    /// Non-title seasons whose points beat the mean `champion_points` across the schedule
+    ///
+    /// Points strictly above the mean would have won an average season; matching it
+    /// doesn't count. In schedule order.
+    #[cfg(feature = "alloc")]
+    pub fn unlucky_seasons() -> Vec<&'static SeasonData> {
+        let champion_total: u32 = Self::SEASONS.iter().map(|s| s.champion_points as u32).sum();
+        let seasons = Self::SEASONS.len() as u32;
+        // points > champion_total / seasons, kept in integers
+        Self::SEASONS
+            .iter()
+            .filter(|s| !s.champion && s.points as u32 * seasons > champion_total)
+            .collect()
+    }
+
+    /// Every season ordered by points, highest first, keeping schedule order on ties
+    #[cfg(feature = "alloc")]
+    pub fn ranked_by_points() -> Vec<&'static SeasonData> {
+        let mut seasons: Vec<&'static SeasonData> = Self::SEASONS.iter().collect();
+        // `sort_by_key` is stable, so equal points stay in schedule order
+        seasons.sort_by_key(|s| core::cmp::Reverse(s.points));
+        seasons
+    }
+
+    /// Running trophy count after each season as `(season, total)`, starting from `start_total`
+    #[cfg(feature = "alloc")]
+    pub fn trophy_trajectory(start_total: u64) -> Vec<(u16, u64)> {
+        Self::SEASONS
+            .iter()
+            .scan(start_total, |total, s| {
+                *total = total.saturating_add(s.champion as u64);
+                Some((s.season, *total))
+            })
+            .collect()
+    }
+
+    /// Full season table as CSV: a header row plus one row per season
+    #[cfg(feature = "alloc")]
+    pub fn to_csv() -> String {
+        let mut csv = String::from("season,position,champion,points,description\n");
+        for s in Self::SEASONS.iter() {
+            csv.push_str(&format!(
+                "{},{},{},{},\"{}\"\n",
+                s.season,
+                s.position,
+                s.champion,
+                s.points,
+                s.description.replace('"', "\"\""),
+            ));
+        }
+        csv
+    }
+
+    /// Rank two seasons by league position, then points; the better season compares `Greater`
+    ///
+    /// Returns `None` if either year has no data.
+    pub fn compare(a: u16, b: u16) -> Option<std::cmp::Ordering> {
+        let a = Self::get_season_data(a)?;
+        let b = Self::get_season_data(b)?;
+        // A lower position is a better finish, so compare positions in reverse
+        Some(b.position.cmp(&a.position).then(a.points.cmp(&b.points)))
+    }
+
+    /// Whether `year` finished higher than the season before it, `None` without data for both
+    ///
+    /// Matching the prior finish doesn't count as an improvement.
+    pub fn improved_over_previous(year: u16) -> Option<bool> {
+        let previous = Self::get_season_data(year.checked_sub(1)?)?;
+        let current = Self::get_season_data(year)?;
+        Some(current.position < previous.position)
+    }
+
+    /// Check the row is for `expected_season` and finishes 1st exactly when it's a title
+    ///
+    /// Run before every play when the `strict` feature is on.
+    pub fn check_consistent(&self, expected_season: u16) -> Result<(), FenerbahceError> {
+        if self.season != expected_season || (*self.position == 1) != self.champion {
+            return Err(FenerbahceError::InconsistentSeasonData(self.season));
+        }
+        Ok(())
+    }
+
+    /// Fewest extra points `year` needed to finish above the champion, `Some(0)` in title years
+    ///
+    /// `None` without data for `year`.
+    pub fn points_to_title(year: u16) -> Option<u16> {
+        let season = Self::get_season_data(year)?;
+        if season.champion {
+            Some(0)
+        } else {
+            Some(season.champion_points.saturating_sub(season.points) + 1)
+        }
+    }
+
+    /// Titles `club` won while Fenerbahçe finished below them
+    pub fn titles_conceded_to(club: &str) -> u8 {
+        Self::SEASONS
+            .iter()
+            .filter(|s| !s.champion && s.champion_team == club)
+            .count() as u8
+    }
+
+    /// Seasons Fenerbahçe is known to have finished above `club`
+    ///
+    /// The table only records the champion, so this counts Fenerbahçe's own title seasons;
+    /// other seasons where they may still have finished higher aren't derivable.
+    pub fn seasons_finished_above(club: &str) -> u8 {
+        Self::SEASONS
+            .iter()
+            .filter(|s| s.champion && s.champion_team != club)
+            .count() as u8
+    }
+
+    /// Longest run of seasons without a title, as `(first, last, seasons)`
+    ///
+    /// A run still going at the end of the table counts too. Ties go to the earliest run, and
+    /// `(0, 0, 0)` means every season brought a title.
+    pub fn longest_drought() -> (u16, u16, u8) {
+        Self::longest_run_in(&Self::SEASONS, |season| !season.champion)
+    }
+
+    /// Longest run of seasons finishing on at least `threshold` points, as `(first, last, seasons)`
+    ///
+    /// Ties go to the earliest run, and `(0, 0, 0)` means no season reached the threshold.
+    pub fn high_scoring_streak(threshold: u16) -> (u16, u16, u8) {
+        Self::longest_run_in(&Self::SEASONS, |season| season.points >= threshold)
+    }
+
+    /// Earliest longest run of consecutive `seasons` matching `pred`, `(0, 0, 0)` if none do
+    fn longest_run_in(seasons: &[SeasonData], pred: impl Fn(&SeasonData) -> bool) -> (u16, u16, u8) {
+        let mut longest = (0, 0, 0);
+        let mut run: Option<(u16, u8)> = None;
+        for season in seasons {
+            if !pred(season) {
+                run = None;
+                continue;
+            }
+            let (first, length) = match run {
+                Some((first, length)) => (first, length + 1),
+                None => (season.season, 1),
+            };
+            run = Some((first, length));
+            if length > longest.2 {
+                longest = (first, season.season, length);
+            }
+        }
+        longest
+    }
+
+    /// Consecutive seasons with the biggest drop in league position, as `(from, to, places)`
+    ///
+    /// `None` if no season finished lower than the one before it. Ties go to the earliest pair.
+    pub fn biggest_decline() -> Option<(u16, u16, i8)> {
+        let mut worst: Option<(u16, u16, i8)> = None;
+        for pair in Self::SEASONS.windows(2) {
+            let (previous, current) = (&pair[0], &pair[1]);
+            if current.season != previous.season + 1 {
+                continue;
+            }
+            let drop = *current.position as i8 - *previous.position as i8;
+            let deeper = match worst {
+                Some((_, _, places)) => drop > places,
+                None => true,
+            };
+            if drop > 0 && deeper {
+                worst = Some((previous.season, current.season, drop));
+            }
+        }
+        worst
+    }
+
+    /// Earliest championship season strictly after `year`, or `None` if no titles remain
+    pub fn next_champion_after(year: u16) -> Option<u16> {
+        Self::SEASONS
+            .iter()
+            .find(|s| s.season > year && s.champion)
+            .map(|s| s.season)
+    }
+}
+
+/// Championships across the whole schedule, counted at compile time
+pub const ERA_TITLES: u64 = count_titles(&SeasonData::SEASONS);
+
+const fn count_titles(seasons: &[SeasonData]) -> u64 {
+    let mut titles = 0;
+    let mut i = 0;
+    while i < seasons.len() {
+        if seasons[i].champion {
+            titles += 1;
+        }
+        i += 1;
+    }
+    titles
+}
+
+/// Joins the original era with the feature-gated future seasons at compile time
+#[cfg(feature = "future-seasons")]
+const fn concat_seasons<const N: usize>(era: &[SeasonData], future: &[SeasonData]) -> [SeasonData; N] {
+    let mut seasons = [era[0]; N];
+    let mut i = 0;
+    while i < era.len() {
+        seasons[i] = era[i];
+        i += 1;
+    }
+    let mut j = 0;
+    while j < future.len() {
+        seasons[era.len() + j] = future[j];
+        j += 1;
+    }
+    seasons
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_legacy_bytes() {
+        // total_trophies: u64, current_season: u16, seasons_played: u8
+        let mut legacy = 18u64.to_le_bytes().to_vec();
+        legacy.extend_from_slice(&2012u16.to_le_bytes());
+        legacy.push(2);
+        assert_eq!(legacy.len(), FenerbahceTracker::LEGACY_SIZE);
+
+        let tracker = FenerbahceTracker::from_legacy_bytes(&legacy).unwrap();
+        assert_eq!(tracker, FenerbahceTracker::replayed_to(2012).unwrap());
+        assert_eq!(tracker.schema_version, FenerbahceTracker::SCHEMA_VERSION);
+
+        assert!(FenerbahceTracker::from_legacy_bytes(&legacy[..10]).is_err());
+    }
+
+    #[cfg(not(feature = "future-seasons"))]
+    #[test]
+    fn test_calls_to_completion() {
+        assert_eq!(FenerbahceTracker::new().calls_to_completion(), 15);
+
+        let mut tracker = FenerbahceTracker::new();
+        for _ in 0..4 {
+            tracker.advance_season().unwrap();
+        }
+        assert_eq!(tracker.calls_to_completion(), 11);
+
+        let complete = FenerbahceTracker::replayed_to(FenerbahceTracker::ENDING_SEASON + 1).unwrap();
+        assert_eq!(complete.calls_to_completion(), 0);
+    }
+
+    #[test]
+    fn test_reset_matches_fresh_tracker() {
+        let mut tracker = FenerbahceTracker::new();
+        for _ in 0..5 {
+            tracker.advance_season().unwrap();
+        }
+        tracker.paused = true;
+        tracker.pending_authority = Some(Pubkey::new_unique());
+
+        tracker.reset();
+        assert_eq!(tracker, FenerbahceTracker::new());
+
+        // Authority and scoring mode are settings, not progress
+        let authority = Pubkey::new_unique();
+        let mut glory = FenerbahceTracker { authority, ..FenerbahceTracker::new_with_mode(ScoringMode::GloryMode) };
+        glory.advance_season().unwrap();
+        assert!(glory.glory_score > 0);
+
+        glory.reset();
+        assert_eq!(glory, FenerbahceTracker { authority, ..FenerbahceTracker::new_with_mode(ScoringMode::GloryMode) });
+    }
+
+    #[test]
+    fn test_from_account_bytes_reads_both_layouts() {
+        let mut legacy = 18u64.to_le_bytes().to_vec();
+        legacy.extend_from_slice(&2012u16.to_le_bytes());
+        legacy.push(2);
+
+        let tracker = FenerbahceTracker::replayed_to(2012).unwrap();
+        let mut current = borsh::to_vec(&tracker).unwrap();
+        current.resize(FenerbahceTracker::SIZE, 0);
+
+        assert_eq!(FenerbahceTracker::from_account_bytes(&legacy).unwrap(), tracker);
+        assert_eq!(FenerbahceTracker::from_account_bytes(&current).unwrap(), tracker);
+    }
+
+    #[test]
+    fn test_tracker_diff() {
+        let before = FenerbahceTracker::new();
+        assert!(before.diff(&before).is_empty());
+        assert_eq!(before.diff(&before), TrackerDiff::default());
+
+        // 2010 was a title season
+        let mut after = before.clone();
+        after.advance_season().unwrap();
+        let diff = before.diff(&after);
+        assert_eq!(diff.trophy_delta, 1);
+        assert_eq!(diff.season_delta, 1);
+        assert_eq!(diff.seasons_played_delta, 1);
+        assert!(diff.title_won);
+        assert!(!diff.is_empty());
+
+        // 2011 wasn't, and the reverse direction reads as negative deltas
+        let mut later = after.clone();
+        later.advance_season().unwrap();
+        assert!(!after.diff(&later).title_won);
+        assert_eq!(after.diff(&later).trophy_delta, 0);
+        assert_eq!(after.diff(&before).trophy_delta, -1);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_format_season() {
+        assert_eq!(format_season(2010), "2010-2011");
+        assert_eq!(format_season(2024), "2024-2025");
+
+        let mut buf = [0u8; SEASON_STRING_MAX_LEN];
+        assert_eq!(format_season_into(2010, &mut buf), "2010-2011");
+        assert_eq!(format_season_into(2024, &mut buf), "2024-2025");
+        assert_eq!(format_season_into(u16::MAX, &mut buf), "65535-65536");
+        assert_eq!(format_season_into(0, &mut buf), "0-1");
+    }
+
+    #[test]
+    fn test_checked_policy_rejects_trophy_overflow() {
+        // 2013 was a title season
+        let mut tracker = FenerbahceTracker::replayed_to(2013).unwrap();
+        tracker.total_trophies = u64::MAX;
+        let original = tracker.clone();
+
+        assert_eq!(
+            tracker.advance_season_with(OverflowPolicy::Checked).unwrap_err(),
+            FenerbahceError::ScoreOverflow
+        );
+        assert_eq!(tracker, original);
+    }
+
+    #[test]
+    fn test_saturating_policy_caps_trophies() {
+        let mut tracker = FenerbahceTracker::replayed_to(2013).unwrap();
+        tracker.total_trophies = u64::MAX - 1;
+        assert!(!tracker.is_score_capped());
+
+        tracker.advance_season_with(OverflowPolicy::Saturating).unwrap();
+        assert_eq!(tracker.total_trophies, u64::MAX);
+        assert!(tracker.is_score_capped());
+
+        // Back to a title season at the cap: the count stays put while the season advances
+        let mut tracker = FenerbahceTracker::replayed_to(2013).unwrap();
+        tracker.total_trophies = u64::MAX;
+        tracker.advance_season_with(OverflowPolicy::Saturating).unwrap();
+        assert_eq!(tracker.total_trophies, u64::MAX);
+        assert_eq!(tracker.current_season, 2014);
+    }
+
+    #[test]
+    fn test_overflow_policies_for_glory_score() {
+        // 2010 was a title, worth 3 glory points
+        let mut tracker = FenerbahceTracker::new_with_mode(ScoringMode::GloryMode);
+        tracker.glory_score = u32::MAX - 1;
+
+        let mut checked = tracker.clone();
+        assert_eq!(
+            checked.advance_season_with(OverflowPolicy::Checked).unwrap_err(),
+            FenerbahceError::ScoreOverflow
+        );
+
+        tracker.advance_season_with(OverflowPolicy::Saturating).unwrap();
+        assert_eq!(tracker.glory_score, u32::MAX);
+        assert_eq!(tracker.seasons_played, 1);
+    }
+
+    #[test]
+    fn test_fenerbahce_tracker_serialization() {
+        let original = FenerbahceTracker {
+            schema_version: FenerbahceTracker::SCHEMA_VERSION,
+            total_trophies: 19,
+            current_season: Season::new(2013),
+            seasons_played: 3,
+            scoring_mode: ScoringMode::GloryMode,
+            glory_score: 8,
+            authority: Pubkey::new_unique(),
+            paused: true,
+            pending_authority: Some(Pubkey::new_unique()),
+            reward_program: Some(Pubkey::new_unique()),
+        };
+        
+        // Serialize using borsh directly
+        let serialized = borsh::to_vec(&original).unwrap();
+        
+        // Deserialize
+        let deserialized = FenerbahceTracker::try_from_slice(&serialized).unwrap();
+        
+        assert_eq!(original, deserialized);
+    }
+
+    #[test]
+    fn test_fenerbahce_tracker_clone_eq() {
+        let tracker = FenerbahceTracker::new();
+        let mut simulated = tracker.clone();
+        assert_eq!(tracker, simulated);
+
+        simulated.current_season = simulated.current_season.next().unwrap();
+        assert_ne!(tracker, simulated);
+    }
+
+    #[test]
+    fn test_fenerbahce_tracker_save_to() {
+        use solana_program::pubkey::Pubkey;
+
+        let key = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let tracker = FenerbahceTracker::new();
+        let expected = borsh::to_vec(&tracker).unwrap();
+
+        let mut lamports = 0;
+        let mut data = vec![0u8; expected.len()];
+        let account = AccountInfo::new(&key, false, true, &mut lamports, &mut data, &owner, false, 0);
+        tracker.save_to(&account).unwrap();
+        assert_eq!(account.data.borrow().as_ref(), expected.as_slice());
+
+        // An undersized buffer is rejected instead of panicking inside borsh
+        let mut lamports = 0;
+        let mut short_data = vec![0u8; expected.len() - 1];
+        let short_account = AccountInfo::new(&key, false, true, &mut lamports, &mut short_data, &owner, false, 0);
+        assert_eq!(
+            tracker.save_to(&short_account),
+            Err(FenerbahceError::SerializationFailed.into())
+        );
+        assert!(short_data.iter().all(|&b| b == 0));
+    }
+
+    #[test]
+    fn test_fenerbahce_tracker_oversized_account() {
+        use solana_program::pubkey::Pubkey;
+
+        let key = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let mut tracker = FenerbahceTracker::new();
+        tracker.pending_authority = Some(Pubkey::new_unique());
+
+        // 20 bytes past the layout, as left behind by a partial migration
+        let mut lamports = 0;
+        let mut data = vec![0xAB; FenerbahceTracker::SIZE + 20];
+        let account = AccountInfo::new(&key, false, true, &mut lamports, &mut data, &owner, false, 0);
+        tracker.save_to(&account).unwrap();
+        assert_eq!(FenerbahceTracker::from_account_bytes(&account.data.borrow()).unwrap(), tracker);
+
+        // Clearing the pending authority zeroes its old bytes but keeps the tail
+        tracker.pending_authority = None;
+        tracker.save_to(&account).unwrap();
+        let data = account.data.borrow();
+        assert_eq!(FenerbahceTracker::from_account_bytes(&data).unwrap(), tracker);
+        assert!(data[53..FenerbahceTracker::SIZE].iter().all(|&b| b == 0));
+        assert!(data[FenerbahceTracker::SIZE..].iter().all(|&b| b == 0xAB));
+    }
+
+    #[test]
+    fn test_fenerbahce_tracker_size() {
+        let tracker = FenerbahceTracker::new();
+        let serialized = borsh::to_vec(&tracker).unwrap();
+        
+        // Should be 1 byte (u8) + 8 bytes (u64) + 2 bytes (u16) + 2 bytes (u16)
+        // + 1 byte (ScoringMode) + 4 bytes (u32) + 32 bytes (Pubkey) + 1 byte (bool) + 2 bytes (None, None) = 53 bytes
+        assert_eq!(serialized.len(), 53);
+
+        // A pending authority and a reward program add their 32-byte keys, reaching the full account size
+        let mut tracker = tracker;
+        tracker.pending_authority = Some(Pubkey::new_unique());
+        tracker.reward_program = Some(Pubkey::new_unique());
+        assert_eq!(borsh::to_vec(&tracker).unwrap().len(), 117);
+        assert_eq!(borsh::to_vec(&tracker).unwrap().len(), FenerbahceTracker::SIZE);
+        assert!(borsh::to_vec(&FenerbahceTracker::new()).unwrap().len() <= FenerbahceTracker::SIZE);
+    }
+
+    #[test]
+    fn test_state_hash() {
+        let tracker = FenerbahceTracker::new();
+        assert_eq!(tracker.state_hash(), FenerbahceTracker::new().state_hash());
+
+        let mut played = tracker.clone();
+        played.advance_season().unwrap();
+        assert_ne!(played.state_hash(), tracker.state_hash());
+
+        let mut paused = tracker.clone();
+        paused.paused = true;
+        assert_ne!(paused.state_hash(), tracker.state_hash());
+    }
+
+    #[test]
+    fn test_rent_exempt_lamports() {
+        let rent = Rent::default();
+        let lamports = FenerbahceTracker::rent_exempt_lamports(&rent);
+
+        // 128 bytes of account overhead plus the 117-byte tracker, at the default rate
+        assert_eq!(lamports, 1_705_200);
+        assert_eq!(lamports, rent.minimum_balance(FenerbahceTracker::SIZE));
+        assert!(lamports > rent.minimum_balance(FenerbahceTracker::LEGACY_SIZE));
+    }
+
+    #[test]
+    fn test_fenerbahce_tracker_unpack_ignores_tail() {
+        let tracker = FenerbahceTracker::new();
+
+        // Account sized for a pending authority, but none is set
+        let mut data = borsh::to_vec(&tracker).unwrap();
+        data.resize(FenerbahceTracker::SIZE, 0);
+
+        assert!(FenerbahceTracker::try_from_slice(&data).is_err());
+        assert_eq!(FenerbahceTracker::unpack(&data).unwrap(), tracker);
+    }
+
+    #[test]
+    fn test_fenerbahce_tracker_new() {
+        let tracker = FenerbahceTracker::new();
+        
+        assert_eq!(tracker.schema_version, FenerbahceTracker::SCHEMA_VERSION);
+        assert_eq!(tracker.total_trophies, FenerbahceTracker::INITIAL_TROPHIES);
+        assert_eq!(tracker.current_season, FenerbahceTracker::STARTING_SEASON);
+        assert_eq!(tracker.seasons_played, 0);
+        assert_eq!(tracker.scoring_mode, ScoringMode::TrophyMode);
+        assert_eq!(tracker.glory_score, 0);
+
+        let glory_tracker = FenerbahceTracker::new_with_mode(ScoringMode::GloryMode);
+        assert_eq!(glory_tracker.scoring_mode, ScoringMode::GloryMode);
+        assert_eq!(glory_tracker.glory_score, 0);
+    }
+
+    #[test]
+    fn test_fenerbahce_tracker_try_new() {
+        assert_eq!(FenerbahceTracker::try_new(2010, 17), Ok(FenerbahceTracker::new()));
+
+        let tracker = FenerbahceTracker::try_new(2013, 18).unwrap();
+        assert_eq!(tracker.current_season, 2013);
+        assert_eq!(tracker.total_trophies, 18);
+        assert_eq!(tracker.seasons_played, 0);
+
+        let last = FenerbahceTracker::ENDING_SEASON;
+        assert!(FenerbahceTracker::try_new(last, 19).is_ok());
+
+        for (season, trophies) in [(2009, 17), (last + 1, 19), (2010, 16), (2013, 0)] {
+            assert_eq!(
+                FenerbahceTracker::try_new(season, trophies),
+                Err(FenerbahceError::InvalidInitParams),
+                "{} with {} trophies",
+                season,
+                trophies
+            );
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_fenerbahce_tracker_season_string() {
+        let tracker = FenerbahceTracker::builder().build().unwrap();
+
+        assert_eq!(tracker.get_season_string(), "2010-2011");
+
+        let tracker2 = FenerbahceTracker::builder().trophies(19).season(2013).played(3).build().unwrap();
+
+        assert_eq!(tracker2.get_season_string(), "2013-2014");
+    }
+
+    #[test]
+    fn test_tracker_builder() {
+        let tracker = FenerbahceTracker::builder().trophies(19).season(2014).played(4).build().unwrap();
+        assert_eq!(Some(tracker), FenerbahceTracker::replayed_to(2014));
+
+        assert_eq!(FenerbahceTracker::builder().build(), Ok(FenerbahceTracker::new()));
+
+        // The end-of-schedule sentinel season is allowed
+        let complete = FenerbahceTracker::ENDING_SEASON + 1;
+        assert!(FenerbahceTracker::builder().season(complete).build().unwrap().is_season_complete());
+    }
+
+    #[test]
+    fn test_tracker_builder_validation() {
+        assert_eq!(
+            FenerbahceTracker::builder().season(2009).build(),
+            Err(FenerbahceError::SeasonOutOfRange(2009))
+        );
+        let past_end = FenerbahceTracker::ENDING_SEASON + 2;
+        assert_eq!(
+            FenerbahceTracker::builder().season(past_end).build(),
+            Err(FenerbahceError::SeasonOutOfRange(past_end))
+        );
+
+        // Can't have played more seasons than have gone by
+        assert_eq!(
+            FenerbahceTracker::builder().season(2012).played(3).build(),
+            Err(FenerbahceError::IntegrityViolation)
+        );
+        // The table has no gaps, so the dataless-season check never rejects an in-range year
+        for year in FenerbahceTracker::STARTING_SEASON..=FenerbahceTracker::ENDING_SEASON {
+            assert!(FenerbahceTracker::builder().season(year).build().is_ok());
+        }
+    }
+
+    #[test]
+    fn test_fenerbahce_tracker_season_from_string() {
+        assert_eq!(FenerbahceTracker::season_from_string("2013-2014"), Ok(2013));
+        assert_eq!(FenerbahceTracker::season_from_string("2010"), Ok(2010));
+
+        // Round-trips with the Season label
+        let tracker = FenerbahceTracker::new();
+        assert_eq!(
+            FenerbahceTracker::season_from_string(&tracker.current_season.to_string()),
+            Ok(tracker.current_season.year())
+        );
+
+        assert_eq!(
+            FenerbahceTracker::season_from_string("2009-2010"),
+            Err(FenerbahceError::SeasonOutOfRange(2009))
+        );
+        assert_eq!(
+            FenerbahceTracker::season_from_string("abcd"),
+            Err(FenerbahceError::BadSeasonString)
+        );
+        assert_eq!(
+            FenerbahceTracker::season_from_string("2013-2015"),
+            Err(FenerbahceError::BadSeasonString)
+        );
+    }
+
+    #[test]
+    fn test_fenerbahce_tracker_season_completion() {
+        let mut tracker = FenerbahceTracker::new();
+        assert!(!tracker.is_season_complete());
+        
+        tracker.current_season = Season::new(2024);
+        assert!(!tracker.is_season_complete());
+        
+        tracker.current_season = Season::new(FenerbahceTracker::ENDING_SEASON);
+        assert!(!tracker.is_season_complete());
+
+        tracker.current_season = Season::new(FenerbahceTracker::ENDING_SEASON + 1);
+        assert!(tracker.is_season_complete());
+    }
+
+    #[cfg(feature = "future-seasons")]
+    #[test]
+    fn test_future_seasons_extend_the_schedule() {
+        assert_eq!(FenerbahceTracker::ENDING_SEASON, 2025);
+        assert_eq!(FenerbahceTracker::new().remaining_seasons(), 16);
+
+        // 2025-2026 is playable instead of marking the end of the schedule
+        let mut tracker = FenerbahceTracker::new();
+        tracker.current_season = Season::new(2025);
+        assert!(!tracker.is_season_complete());
+        assert_eq!(tracker.remaining_seasons(), 1);
+
+        tracker.current_season = Season::new(2026);
+        assert!(tracker.is_season_complete());
+        assert_eq!(tracker.remaining_seasons(), 0);
+    }
+
+    #[test]
+    fn test_fenerbahce_tracker_titles_this_era() {
+        assert_eq!(FenerbahceTracker::new().titles_this_era(), 0);
+
+        let complete = FenerbahceTracker::replayed_to(FenerbahceTracker::ENDING_SEASON + 1).unwrap();
+        #[cfg(not(feature = "future-seasons"))]
+        assert_eq!(complete.titles_this_era(), 2);
+        #[cfg(feature = "alloc")]
+        assert_eq!(complete.titles_this_era(), SeasonData::championship_years().len() as u64);
+    }
+
+    #[test]
+    fn test_championship_count_at_starting_trophies() {
+        let mut tracker = FenerbahceTracker::new();
+        assert_eq!(tracker.total_trophies, FenerbahceTracker::INITIAL_TROPHIES);
+        assert_eq!(tracker.titles_this_era(), 0);
+
+        // A rewound tracker below the starting count must not underflow
+        tracker.total_trophies = FenerbahceTracker::INITIAL_TROPHIES - 1;
+        assert_eq!(tracker.titles_this_era(), 0);
+    }
+
+    #[test]
+    fn test_run_length_tracks_season_table() {
+        let total = SeasonData::SEASONS.len() as u16;
+        assert_eq!(FenerbahceTracker::ENDING_SEASON - FenerbahceTracker::STARTING_SEASON + 1, total);
+
+        let mut tracker = FenerbahceTracker::new();
+        assert_eq!(tracker.remaining_seasons(), total);
+        assert_eq!(tracker.progress_percent(), 0);
+
+        tracker.current_season = Season::new(tracker.current_season.year() + total - 1);
+        assert_eq!(tracker.remaining_seasons(), 1);
+        assert!(!tracker.is_season_complete());
+
+        tracker.current_season = tracker.current_season.next().unwrap();
+        assert_eq!(tracker.remaining_seasons(), 0);
+        assert_eq!(tracker.progress_percent(), 100);
+        assert!(tracker.is_season_complete());
+    }
+
+    #[test]
+    fn test_fenerbahce_tracker_progress_percent() {
+        let mut tracker = FenerbahceTracker::new();
+        tracker.current_season = Season::new(2013); // 3 of 15 seasons played
+
+        #[cfg(not(feature = "future-seasons"))]
+        assert_eq!(tracker.progress_percent(), 20);
+        assert_eq!(tracker.remaining_seasons(), FenerbahceTracker::ENDING_SEASON + 1 - 2013);
+    }
+
+    #[test]
+    fn test_fenerbahce_tracker_upcoming_season() {
+        let mut tracker = FenerbahceTracker::new();
+        assert_eq!(tracker.upcoming_season().unwrap().season, 2010);
+
+        tracker.current_season = Season::new(2017);
+        tracker.seasons_played = 7;
+        assert_eq!(tracker.upcoming_season().unwrap().season, 2017);
+
+        tracker.current_season = Season::new(FenerbahceTracker::ENDING_SEASON + 1);
+        tracker.seasons_played = SeasonData::SEASONS.len() as u16;
+        assert!(tracker.upcoming_season().is_none());
+    }
+
+    #[test]
+    fn test_fenerbahce_tracker_last_played_season() {
+        let mut tracker = FenerbahceTracker::new();
+        assert!(tracker.last_played_season().is_none());
+
+        tracker.current_season = Season::new(2014);
+        tracker.seasons_played = 4;
+        assert_eq!(tracker.last_played_season().unwrap().season, 2013);
+
+        tracker.current_season = Season::new(FenerbahceTracker::ENDING_SEASON + 1);
+        tracker.seasons_played = SeasonData::SEASONS.len() as u16;
+        assert_eq!(tracker.last_played_season().unwrap().season, FenerbahceTracker::ENDING_SEASON);
+    }
+
+    #[test]
+    fn test_last_played_season_follows_advance_season() {
+        let mut tracker = FenerbahceTracker::new();
+
+        let played = tracker.advance_season().unwrap();
+        let last = tracker.last_played_season().unwrap();
+        assert_eq!(last.season, 2010);
+        assert!(std::ptr::eq(last, played));
+
+        while !tracker.is_season_complete() {
+            tracker.advance_season().unwrap();
+        }
+        #[cfg(not(feature = "future-seasons"))]
+        assert_eq!(tracker.last_played_season().unwrap().season, 2024);
+        assert!(tracker.upcoming_season().is_none());
+    }
+
+    #[test]
+    fn test_fenerbahce_tracker_replayed_to() {
+        let fresh = FenerbahceTracker::replayed_to(2010).unwrap();
+        assert_eq!(fresh.total_trophies, 17);
+        assert_eq!(fresh.seasons_played, 0);
+
+        let after_2013 = FenerbahceTracker::replayed_to(2014).unwrap();
+        assert_eq!(after_2013.total_trophies, 19);
+        assert_eq!(after_2013.current_season, 2014);
+        assert_eq!(after_2013.seasons_played, 4);
+
+        let complete = FenerbahceTracker::replayed_to(FenerbahceTracker::ENDING_SEASON + 1).unwrap();
+        assert!(complete.is_season_complete());
+        assert_eq!(complete.seasons_played, SeasonData::SEASONS.len() as u16);
+
+        assert!(FenerbahceTracker::replayed_to(2009).is_none());
+
+        // GloryMode replays the glory points too, matching a tracker actually played that way
+        let mut played = FenerbahceTracker::new_with_mode(ScoringMode::GloryMode);
+        while !played.is_season_complete() {
+            played.advance_season().unwrap();
+            assert_eq!(
+                FenerbahceTracker::replayed_to_with_mode(played.current_season.year(), ScoringMode::GloryMode),
+                Some(played.clone())
+            );
+        }
+        assert!(FenerbahceTracker::replayed_to(FenerbahceTracker::ENDING_SEASON + 2).is_none());
     }
 
     #[test]
     fn test_season_data_lookup() {
         // Test championship seasons
         let season_2010 = SeasonData::get_season_data(2010).unwrap();
-        assert_eq!(season_2010.position, 1);
+        assert_eq!(*season_2010.position, 1);
         assert!(season_2010.champion);
         assert_eq!(season_2010.points, 82);
         
         let season_2013 = SeasonData::get_season_data(2013).unwrap();
-        assert_eq!(season_2013.position, 1);
+        assert_eq!(*season_2013.position, 1);
         assert!(season_2013.champion);
-        assert_eq!(season_2013.points, 75);
+        assert_eq!(season_2013.points, 74);
         
         // Test non-championship season
         let season_2011 = SeasonData::get_season_data(2011).unwrap();
-        assert_eq!(season_2011.position, 2);
+        assert_eq!(*season_2011.position, 2);
         assert!(!season_2011.champion);
-        assert_eq!(season_2011.points, 71);
+        assert_eq!(season_2011.points, 68);
         
         // Test invalid season
         assert!(SeasonData::get_season_data(2009).is_none());
         assert!(SeasonData::get_season_data(2030).is_none());
     }
 
+    #[test]
+    fn test_season_descriptions_match_fields() {
+        for season in SeasonData::SEASONS.iter() {
+            let is_title = season.description.starts_with("🏆");
+            assert_eq!(is_title, season.champion,
+                       "Season {} trophy emoji doesn't match champion flag", season.season);
+
+            // Title seasons read "🏆 CHAMPIONS!", everything else leads with an ordinal like "2nd place"
+            let described_position: u8 = if is_title {
+                1
+            } else {
+                let digits: String = season.description.chars().take_while(|c| c.is_ascii_digit()).collect();
+                let suffix = &season.description[digits.len()..];
+                assert!(["st ", "nd ", "rd ", "th "].iter().any(|s| suffix.starts_with(s)),
+                        "Season {} description should start with an ordinal", season.season);
+                digits.parse().unwrap()
+            };
+
+            assert_eq!(described_position, *season.position,
+                       "Season {} description doesn't match its position", season.season);
+
+            // Non-title descriptions name the champion, and title seasons belong to Fenerbahçe
+            if season.champion {
+                assert_eq!(season.champion_team, "Fenerbahçe");
+            } else {
+                assert!(season.description.contains(&format!("champion {}", season.champion_team)),
+                        "Season {} description doesn't name champion {}", season.season, season.champion_team);
+                assert!(season.description.contains(&format!("{} points behind", season.points_behind())),
+                        "Season {} description doesn't match its points gap", season.season);
+                assert!(season.description.contains(&format!("({} pts)", season.champion_points)),
+                        "Season {} description doesn't match the champion's points", season.season);
+            }
+        }
+    }
+
+    #[test]
+    fn test_season_data_try_get() {
+        let season_2013 = SeasonData::try_get(2013).unwrap();
+        assert_eq!(season_2013.season, 2013);
+        assert!(season_2013.champion);
+
+        assert_eq!(
+            SeasonData::try_get(2030).err(),
+            Some(FenerbahceError::SeasonOutOfRange(2030))
+        );
+    }
+
+    #[test]
+    fn test_season_glory_points() {
+        assert_eq!(SeasonData::get_season_data(2010).unwrap().glory_points(), 3);
+        assert_eq!(SeasonData::get_season_data(2011).unwrap().glory_points(), 1);
+        assert_eq!(SeasonData::get_season_data(2016).unwrap().glory_points(), 0);
+        assert_eq!(SeasonData::get_season_data(2019).unwrap().glory_points(), 0);
+    }
+
+    #[cfg(not(feature = "future-seasons"))]
+    #[test]
+    fn test_era_titles() {
+        assert_eq!(ERA_TITLES, 2);
+        assert_eq!(FenerbahceTracker::INITIAL_TROPHIES + ERA_TITLES, 19);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_era_titles_matches_scan() {
+        assert_eq!(ERA_TITLES, SeasonData::championship_years().len() as u64);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_championship_years() {
+        assert_eq!(SeasonData::championship_years(), vec![2010, 2013]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_titles_lost_to() {
+        #[cfg(not(feature = "future-seasons"))]
+        assert_eq!(
+            SeasonData::titles_lost_to("Galatasaray"),
+            vec![2011, 2012, 2014, 2017, 2018, 2022, 2023, 2024]
+        );
+        assert_eq!(SeasonData::titles_lost_to("Beşiktaş"), vec![2015, 2016, 2020]);
+        assert_eq!(SeasonData::titles_lost_to("Fenerbahçe"), vec![2010, 2013]);
+        assert!(SeasonData::titles_lost_to("Göztepe").is_empty());
+    }
+
+    #[test]
+    fn test_points_behind() {
+        assert_eq!(SeasonData::get_season_data(2018).unwrap().points_behind(), 23);
+        assert_eq!(SeasonData::get_season_data(2020).unwrap().points_behind(), 2);
+
+        // Title years contribute nothing
+        for season in SeasonData::SEASONS.iter().filter(|s| s.champion) {
+            assert_eq!(season.points_behind(), 0);
+        }
+
+        // 9 + 10 + 3 + 5 + 13 + 3 + 23 + 13 + 2 + 8 + 5 + 3 + 11
+        #[cfg(not(feature = "future-seasons"))]
+        assert_eq!(SeasonData::total_points_behind(), 108);
+    }
+
+    #[test]
+    fn test_season_tier() {
+        let tier = |year| SeasonData::get_season_data(year).unwrap().tier();
+
+        assert_eq!(tier(2010), SeasonTier::Champion);
+        // 99 points, 3 behind
+        assert_eq!(tier(2023), SeasonTier::TitleContender);
+        // Third place is Europe regardless of the gap
+        assert_eq!(tier(2016), SeasonTier::Europe);
+        // Exactly 5 behind still counts, a wider gap drops to Europe
+        assert_eq!(tier(2015), SeasonTier::TitleContender);
+        assert_eq!(tier(2011), SeasonTier::Europe);
+        assert_eq!(tier(2018), SeasonTier::Disappointment);
+        assert_eq!(tier(2019), SeasonTier::Disappointment);
+    }
+
+    #[test]
+    fn test_heartbreak_season() {
+        let heartbreak = SeasonData::heartbreak_season().unwrap();
+        assert_eq!(heartbreak.season, 2023);
+        assert_eq!(heartbreak.points, 99);
+        assert_eq!(heartbreak.points_wasted(), 99);
+
+        // Title seasons never count as wasted
+        assert_eq!(SeasonData::get_season_data(2013).unwrap().points_wasted(), 0);
+    }
+
+    #[test]
+    fn test_season_boundaries() {
+        let first = Season::new(FenerbahceTracker::STARTING_SEASON);
+        assert!(first.is_in_range());
+        assert!(!Season::new(FenerbahceTracker::STARTING_SEASON - 1).is_in_range());
+        assert_eq!(first.to_string(), "2010-2011");
+        #[cfg(feature = "alloc")]
+        assert_eq!(first.to_string_label(), "2010-2011");
+
+        let last = Season::new(FenerbahceTracker::ENDING_SEASON);
+        let past_end = last.next().unwrap();
+        assert!(last.is_in_range());
+        assert_eq!(past_end, FenerbahceTracker::ENDING_SEASON + 1);
+        assert!(!past_end.is_in_range());
+        assert!(past_end > FenerbahceTracker::ENDING_SEASON);
+
+        assert_eq!(Season::new(u16::MAX).next(), Err(FenerbahceError::SeasonOutOfRange(u16::MAX)));
+        assert_eq!(u16::from(Season::new(2013)), 2013);
+        assert_eq!(Season::new(2013).year(), 2013);
+    }
+
+    #[test]
+    fn test_season_serializes_as_u16() {
+        assert_eq!(borsh::to_vec(&Season::new(2013)).unwrap(), borsh::to_vec(&2013u16).unwrap());
+        assert_eq!(Season::try_from_slice(&2013u16.to_le_bytes()).unwrap(), 2013);
+    }
+
+    #[test]
+    fn test_position_validation() {
+        assert_eq!(Position::new(0), Err(FenerbahceError::InvalidPosition(0)));
+        assert_eq!(Position::try_from(21), Err(FenerbahceError::InvalidPosition(21)));
+        assert_eq!(Position::new(200), Err(FenerbahceError::InvalidPosition(200)));
+
+        for value in 1..=Position::LEAGUE_SIZE {
+            let position = Position::new(value).unwrap();
+            assert_eq!(*position, value);
+            assert_eq!(u8::from(position), value);
+        }
+
+        // The const season table is built through the same check
+        assert!(SeasonData::SEASONS.iter().all(|s| (1..=Position::LEAGUE_SIZE).contains(&*s.position)));
+    }
+
+    #[cfg(not(feature = "future-seasons"))]
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_era_summary() {
+        let summary = SeasonData::era_summary();
+
+        assert_eq!(summary.total_seasons, 15);
+        assert_eq!(summary.titles, 2);
+        assert_eq!(summary.runner_ups, 9);
+        assert_eq!(summary.best_points, 99);
+        assert_eq!(summary.worst_position, 7);
+        // 1086 points over 15 seasons
+        assert!((summary.average_points - 72.4).abs() < 1e-9);
+        assert_eq!(summary.champion_years, vec![2010, 2013]);
+    }
+
+    #[cfg(all(feature = "alloc", not(feature = "future-seasons")))]
+    #[test]
+    fn test_era_summary_display() {
+        assert_eq!(
+            SeasonData::era_summary().to_string(),
+            "Seasons: 15\n\
+             Titles: 2 (2010, 2013)\n\
+             Runner-ups: 9\n\
+             Best points: 99\n\
+             Worst position: 7\n\
+             Average points: 72.4"
+        );
+    }
+
+    #[test]
+    fn test_season_tier_display() {
+        assert_eq!(SeasonTier::Champion.to_string(), "Champion");
+        assert_eq!(SeasonTier::TitleContender.to_string(), "Title Contender");
+        assert_eq!(SeasonData::try_get(2018).unwrap().tier().to_string(), "Disappointment");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_era_summary_to_json() {
+        let summary = SeasonData::era_summary();
+        let json: serde_json::Value = serde_json::from_str(&summary.to_json()).unwrap();
+
+        assert_eq!(json["total_seasons"], summary.total_seasons);
+        assert_eq!(json["titles"], summary.titles);
+        assert_eq!(json["runner_ups"], summary.runner_ups);
+        assert_eq!(json["best_points"], summary.best_points);
+        assert_eq!(json["worst_position"], summary.worst_position);
+        assert_eq!(json["average_points"].as_f64().unwrap(), summary.average_points);
+        assert_eq!(json["champion_years"], serde_json::json!(summary.champion_years));
+        assert_eq!(json.as_object().unwrap().len(), 7);
+
+        // An empty summary still produces valid JSON
+        let empty = EraSummary {
+            total_seasons: 0,
+            titles: 0,
+            runner_ups: 0,
+            best_points: 0,
+            worst_position: 0,
+            average_points: f64::NAN,
+            champion_years: vec![],
+        };
+        let json: serde_json::Value = serde_json::from_str(&empty.to_json()).unwrap();
+        assert!(json["average_points"].is_null());
+        assert_eq!(json["champion_years"], serde_json::json!([]));
+    }
+
+    #[test]
+    fn test_position_counts() {
+        let counts = SeasonData::position_counts();
+
+        #[cfg(not(feature = "future-seasons"))]
+        assert_eq!(counts, [0, 2, 9, 2, 0, 0, 1, 1]);
+        assert_eq!(counts[0], 0);
+        assert_eq!(counts.iter().map(|&c| c as usize).sum::<usize>(), SeasonData::SEASONS.len());
+    }
+
+    #[test]
+    fn test_longest_runner_up_streak() {
+        #[cfg(not(feature = "future-seasons"))]
+        assert_eq!(SeasonData::longest_runner_up_streak(), (2021, 2024, 4));
+
+        let season = |season, position| SeasonData {
+            season,
+            position: Position::new(position).unwrap(),
+            champion: position == 1,
+            points: 0,
+            champion_points: 0,
+            champion_team: "",
+            description: "",
+        };
+
+        // No runner-up finishes at all
+        assert_eq!(SeasonData::longest_runner_up_streak_in(&[]), (0, 0, 0));
+        assert_eq!(SeasonData::longest_runner_up_streak_in(&[season(2010, 1), season(2011, 3)]), (0, 0, 0));
+
+        // A lone runner-up season is a streak of one
+        assert_eq!(SeasonData::longest_runner_up_streak_in(&[season(2010, 1), season(2011, 2)]), (2011, 2011, 1));
+
+        // Ties keep the earlier run
+        let tied = [season(2010, 2), season(2011, 2), season(2012, 1), season(2013, 2), season(2014, 2)];
+        assert_eq!(SeasonData::longest_runner_up_streak_in(&tied), (2010, 2011, 2));
+    }
+
+    #[test]
+    fn test_points_variance() {
+        // Mean 72.4, squared deviations summing to 2385.6
+        #[cfg(not(feature = "future-seasons"))]
+        {
+            assert!((SeasonData::points_variance() - 159.04).abs() < 1e-9);
+            assert!((SeasonData::points_std_dev() - 12.611106).abs() < 1e-6);
+        }
+
+        assert!(SeasonData::points_variance() > 0.0);
+        assert!((SeasonData::points_std_dev().powi(2) - SeasonData::points_variance()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_title_rate() {
+        #[cfg(not(feature = "future-seasons"))]
+        assert!((SeasonData::title_rate() - 2.0 / 15.0).abs() < f64::EPSILON);
+
+        assert_eq!(FenerbahceTracker::new().title_rate_so_far(), 0.0);
+
+        let after_2013 = FenerbahceTracker::replayed_to(2014).unwrap();
+        assert!((after_2013.title_rate_so_far() - 0.5).abs() < f64::EPSILON);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_owned_season_data() {
+        let season = SeasonData::try_get(2013).unwrap();
+        let owned = season.to_owned_data();
+
+        assert_eq!(owned, OwnedSeasonData::from(season));
+        assert_eq!(owned.season, season.season);
+        assert_eq!(owned.position, season.position);
+        assert_eq!(owned.champion, season.champion);
+        assert_eq!(owned.points, season.points);
+        assert_eq!(owned.champion_points, season.champion_points);
+        assert_eq!(owned.champion_team, season.champion_team);
+        assert_eq!(owned.description, season.description);
+
+        // Owned data can outlive any borrow and move to another thread
+        let handle = std::thread::spawn(move || owned.points);
+        assert_eq!(handle.join().unwrap(), 74);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_season_data_to_csv() {
+        let csv = SeasonData::to_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(lines.len(), SeasonData::SEASONS.len() + 1);
+        #[cfg(not(feature = "future-seasons"))]
+        assert_eq!(lines.len(), 16);
+        assert_eq!(lines[0], "season,position,champion,points,description");
+
+        // Championship rows show `true`, with the comma-laden description quoted
+        assert!(lines[1].starts_with("2010,1,true,82,\"🏆 CHAMPIONS!"));
+        assert!(lines[4].starts_with("2013,1,true,74,\""));
+        assert!(lines[2].starts_with("2011,2,false,68,\""));
+        assert!(lines[1].ends_with('"'));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_unlucky_seasons() {
+        let unlucky: Vec<u16> = SeasonData::unlucky_seasons().iter().map(|s| s.season).collect();
+
+        // Mean champion_points is 1194 / 15 = 79.6
+        assert!(unlucky.contains(&2023));
+        assert!(unlucky.contains(&2020));
+        assert!(!unlucky.contains(&2018));
+        assert!(!unlucky.contains(&2014));
+        // Title seasons never count, however many points they took
+        assert!(!unlucky.contains(&2010));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_season_data_ranked_by_points() {
+        let ranked = SeasonData::ranked_by_points();
+        let years: Vec<u16> = ranked.iter().map(|s| s.season).collect();
+
+        assert_eq!(ranked.len(), SeasonData::SEASONS.len());
+        assert_eq!((ranked[0].season, ranked[0].points), (2023, 99));
+        assert!(ranked.windows(2).all(|w| w[0].points >= w[1].points));
+
+        // 2010 and 2020 both had 82 points, 2013-2015 all had 74
+        let position = |year| years.iter().position(|&y| y == year).unwrap();
+        assert!(position(2010) < position(2020));
+        assert!(position(2013) < position(2014) && position(2014) < position(2015));
+
+        #[cfg(not(feature = "future-seasons"))]
+        assert_eq!((ranked[14].season, ranked[14].points), (2018, 46));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_trophy_trajectory() {
+        let trajectory = SeasonData::trophy_trajectory(FenerbahceTracker::INITIAL_TROPHIES);
+
+        let mut expected = vec![(2010, 18), (2011, 18), (2012, 18)];
+        expected.extend((2013..=2024).map(|year| (year, 19)));
+        #[cfg(not(feature = "future-seasons"))]
+        assert_eq!(trajectory, expected);
+        assert_eq!(trajectory[..expected.len()], expected[..]);
+
+        // The final point agrees with playing every season
+        let final_total = trajectory.last().unwrap().1;
+        assert_eq!(final_total, FenerbahceTracker::replayed_to(FenerbahceTracker::ENDING_SEASON + 1).unwrap().total_trophies);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_season_data_filter() {
+        let years = |seasons: Vec<&SeasonData>| seasons.iter().map(|s| s.season).collect::<Vec<_>>();
+
+        assert_eq!(
+            years(SeasonData::filter(|s| s.points >= 80)),
+            vec![2010, 2020, 2022, 2023, 2024]
+        );
+        assert_eq!(years(SeasonData::filter(|s| *s.position > 3)), vec![2018, 2019]);
+        assert!(SeasonData::filter(|_| false).is_empty());
+    }
+
+    #[test]
+    fn test_season_compare() {
+        use std::cmp::Ordering;
+
+        assert_eq!(SeasonData::compare(2010, 2011), Some(Ordering::Greater));
+        assert_eq!(SeasonData::compare(2011, 2010), Some(Ordering::Less));
+        assert_eq!(SeasonData::compare(2013, 2018), Some(Ordering::Greater));
+        // Same position, more points wins
+        assert_eq!(SeasonData::compare(2023, 2022), Some(Ordering::Greater));
+        assert_eq!(SeasonData::compare(2014, 2014), Some(Ordering::Equal));
+
+        assert_eq!(SeasonData::compare(2010, 2009), None);
+        assert_eq!(SeasonData::compare(2030, 2010), None);
+    }
+
+    #[test]
+    fn test_biggest_decline() {
+        // 2nd in 2017-2018, 6th the season after
+        assert_eq!(SeasonData::biggest_decline(), Some((2017, 2018, 4)));
+    }
+
+    #[test]
+    fn test_check_consistent() {
+        for season in SeasonData::SEASONS.iter() {
+            assert_eq!(season.check_consistent(season.season), Ok(()));
+        }
+
+        // Marked champion while finishing 2nd
+        let bad = SeasonData { champion: true, ..SeasonData::SEASONS[1] };
+        assert_eq!(bad.check_consistent(2011), Err(FenerbahceError::InconsistentSeasonData(2011)));
+
+        let good = SeasonData::SEASONS[0];
+        assert_eq!(good.check_consistent(2011), Err(FenerbahceError::InconsistentSeasonData(2010)));
+    }
+
+    #[test]
+    fn test_rivalry_record() {
+        let conceded = SeasonData::SEASONS
+            .iter()
+            .filter(|s| s.description.contains("champion Galatasaray"))
+            .count() as u8;
+        assert_eq!(SeasonData::titles_conceded_to("Galatasaray"), conceded);
+        #[cfg(not(feature = "future-seasons"))]
+        assert_eq!(conceded, 8);
+
+        assert_eq!(SeasonData::titles_conceded_to("Fenerbahçe"), 0);
+        assert_eq!(SeasonData::seasons_finished_above("Galatasaray"), ERA_TITLES as u8);
+        assert_eq!(SeasonData::seasons_finished_above("Fenerbahçe"), 0);
+    }
+
+    #[test]
+    fn test_high_scoring_streak() {
+        // 82, 73, 80, 99 and 84 points from 2020-2021 on
+        #[cfg(not(feature = "future-seasons"))]
+        assert_eq!(SeasonData::high_scoring_streak(70), (2020, 2024, 5));
+
+        let (first, last, seasons) = SeasonData::high_scoring_streak(70);
+        assert_eq!(seasons as u16, last - first + 1);
+        assert!((first..=last).all(|year| SeasonData::get_season_data(year).unwrap().points >= 70));
+
+        // Nobody got to 100
+        assert_eq!(SeasonData::high_scoring_streak(100), (0, 0, 0));
+    }
+
+    #[test]
+    fn test_longest_drought() {
+        let (first, last, seasons) = SeasonData::longest_drought();
+        assert_eq!(seasons as u16, last - first + 1);
+        assert!((first..=last).all(|year| !SeasonData::get_season_data(year).unwrap().champion));
+
+        // No title since 2013-2014
+        #[cfg(not(feature = "future-seasons"))]
+        assert_eq!((first, last, seasons), (2014, 2024, 11));
+    }
+
+    #[test]
+    fn test_points_to_title() {
+        // 99 points against Galatasaray's 102
+        assert_eq!(SeasonData::points_to_title(2023), Some(4));
+        assert_eq!(SeasonData::points_to_title(2010), Some(0));
+        assert_eq!(SeasonData::points_to_title(1999), None);
+    }
+
+    #[test]
+    fn test_improved_over_previous() {
+        // 1st after 2nd
+        assert_eq!(SeasonData::improved_over_previous(2013), Some(true));
+        // 6th after 2nd
+        assert_eq!(SeasonData::improved_over_previous(2018), Some(false));
+        // 2nd after 2nd is no improvement
+        assert_eq!(SeasonData::improved_over_previous(2012), Some(false));
+
+        assert_eq!(SeasonData::improved_over_previous(2010), None);
+        assert_eq!(SeasonData::improved_over_previous(1999), None);
+        assert_eq!(SeasonData::improved_over_previous(0), None);
+    }
+
+    #[test]
+    fn test_next_champion_after() {
+        assert_eq!(SeasonData::next_champion_after(2009), Some(2010));
+        assert_eq!(SeasonData::next_champion_after(2010), Some(2013));
+        assert_eq!(SeasonData::next_champion_after(2013), None);
+        assert_eq!(SeasonData::next_champion_after(FenerbahceTracker::ENDING_SEASON), None);
+    }
+
     #[test]
     fn test_seasons_data_count() {
+        #[cfg(not(feature = "future-seasons"))]
         assert_eq!(SeasonData::SEASONS.len(), 15);
+        #[cfg(feature = "future-seasons")]
+        assert_eq!(SeasonData::SEASONS.len(), 15 + SeasonData::FUTURE_SEASONS.len());
         
-        // Verify all seasons from 2010 to 2024 are present
-        for year in 2010..=2024 {
+        // Verify every season in the schedule is present
+        for year in FenerbahceTracker::STARTING_SEASON..=FenerbahceTracker::ENDING_SEASON {
             assert!(SeasonData::get_season_data(year).is_some(), 
                    "Season {} should be present", year);
         }
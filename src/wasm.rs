@@ -0,0 +1,66 @@
+use wasm_bindgen::prelude::*;
+
+use crate::state::SeasonData;
+
+/// Description of the season starting in `year`, e.g. 2013 for 2013-2014
+///
+/// JS: `season_description(year: number): string | undefined`
+#[wasm_bindgen]
+pub fn season_description(year: u16) -> Option<String> {
+    SeasonData::get_season_data(year).map(|s| s.description.to_string())
+}
+
+/// Championship seasons as a JSON array, e.g. `[2010,2013]`
+///
+/// JS: `championship_years_json(): string`
+#[wasm_bindgen]
+pub fn championship_years_json() -> String {
+    let years: Vec<String> = SeasonData::championship_years()
+        .iter()
+        .map(|year| year.to_string())
+        .collect();
+    format!("[{}]", years.join(","))
+}
+
+/// Trophy count after playing every season from `start` to the end of the schedule
+///
+/// JS: `simulate_final(start: number, initial: bigint): bigint`
+#[wasm_bindgen]
+pub fn simulate_final(start: u16, initial: u64) -> u64 {
+    let titles = SeasonData::SEASONS
+        .iter()
+        .filter(|s| s.season >= start && s.champion)
+        .count() as u64;
+    initial.saturating_add(titles)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::FenerbahceTracker;
+
+    #[test]
+    fn test_season_description() {
+        assert_eq!(
+            season_description(2013).as_deref(),
+            Some(SeasonData::get_season_data(2013).unwrap().description)
+        );
+        assert!(season_description(2009).is_none());
+    }
+
+    #[test]
+    fn test_championship_years_json() {
+        assert_eq!(championship_years_json(), "[2010,2013]");
+    }
+
+    #[test]
+    fn test_simulate_final() {
+        let start = FenerbahceTracker::STARTING_SEASON;
+        let initial = FenerbahceTracker::INITIAL_TROPHIES;
+
+        assert_eq!(simulate_final(start, initial), 19);
+        assert_eq!(simulate_final(2011, initial), 18);
+        assert_eq!(simulate_final(2014, initial), 17);
+        assert_eq!(simulate_final(start, u64::MAX), u64::MAX);
+    }
+}
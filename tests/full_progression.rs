@@ -0,0 +1,101 @@
+// Plays the whole schedule through a banks client, checking the account after every season
+// against a progression worked out from `SeasonData` alone
+
+use counter_program::{
+    client::{get_tracker_address, initialize_tracker, play_season},
+    instruction::FenerbahceInstruction,
+    processor::Processor,
+    state::{FenerbahceTracker, SeasonData},
+};
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
+use solana_program_test::{processor, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    instruction::Instruction,
+    signature::Signer,
+    transaction::{Transaction, TransactionError},
+};
+
+fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let instruction = FenerbahceInstruction::unpack(instruction_data)?;
+    Processor::process(program_id, accounts, instruction)
+}
+
+async fn send(context: &mut ProgramTestContext, instruction: Instruction) -> Result<(), TransactionError> {
+    let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        recent_blockhash,
+    );
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .map_err(|err| err.unwrap())
+}
+
+async fn fetch_data(context: &mut ProgramTestContext, tracker: &Pubkey) -> Vec<u8> {
+    context.banks_client.get_account(*tracker).await.unwrap().expect("tracker account should exist").data
+}
+
+/// `(total_trophies, current_season, seasons_played)` read at their fixed offsets in the layout
+fn raw_fields(data: &[u8]) -> (u64, u16, u16) {
+    (
+        u64::from_le_bytes(data[1..9].try_into().unwrap()),
+        u16::from_le_bytes(data[9..11].try_into().unwrap()),
+        u16::from_le_bytes(data[11..13].try_into().unwrap()),
+    )
+}
+
+#[tokio::test]
+async fn test_full_progression_matches_season_data() {
+    let program_id = Pubkey::new_unique();
+    let program_test = ProgramTest::new("counter_program", program_id, processor!(process_instruction));
+    let mut context = program_test.start_with_context().await;
+    let tracker = get_tracker_address(&program_id);
+    let payer = context.payer.pubkey();
+
+    // Creates the tracker through a CPI to the system program
+    send(&mut context, initialize_tracker(&program_id, &tracker, &payer)).await.unwrap();
+    let data = fetch_data(&mut context, &tracker).await;
+    assert_eq!(
+        raw_fields(&data),
+        (FenerbahceTracker::INITIAL_TROPHIES, FenerbahceTracker::STARTING_SEASON, 0)
+    );
+
+    let mut expected_trophies = FenerbahceTracker::INITIAL_TROPHIES;
+    for (played, season) in SeasonData::SEASONS.iter().enumerate() {
+        send(&mut context, play_season(&program_id, &tracker)).await.unwrap();
+
+        if season.champion {
+            expected_trophies += 1;
+        }
+        let expected = (expected_trophies, season.season + 1, played as u16 + 1);
+
+        let data = fetch_data(&mut context, &tracker).await;
+        assert_eq!(raw_fields(&data), expected, "after {}", season.season);
+
+        let tracker_data = FenerbahceTracker::unpack(&data).unwrap();
+        assert_eq!(
+            (tracker_data.total_trophies, tracker_data.current_season.year(), tracker_data.seasons_played),
+            expected,
+            "after {}",
+            season.season
+        );
+        assert_eq!(tracker_data.authority, payer);
+    }
+
+    let data = fetch_data(&mut context, &tracker).await;
+    let final_tracker = FenerbahceTracker::unpack(&data).unwrap();
+    assert!(final_tracker.is_season_complete());
+    assert_eq!(final_tracker.titles_this_era(), SeasonData::SEASONS.iter().filter(|s| s.champion).count() as u64);
+
+    // Another play once the schedule is done leaves the account as it was
+    send(&mut context, play_season(&program_id, &tracker)).await.unwrap();
+    assert_eq!(fetch_data(&mut context, &tracker).await, data);
+}
@@ -0,0 +1,708 @@
+#[cfg(feature = "client-rpc")]
+use counter_program::client::required_init_lamports;
+use counter_program::{
+    client::{
+        airdrop_shortfall, decode_season_log, get_season_log_address, get_tracker_address, initialize_and_play,
+        initialize_if_needed, initialize_tracker, initialize_tracker_with_mode, migrate_tracker, pause, play_season,
+        play_season_logged, play_season_with_reward_hook, run_all_over, set_reward_program, snapshot,
+    },
+    error::FenerbahceError,
+    hook::TitleWon,
+    instruction::FenerbahceInstruction,
+    processor::{Processor, SNAPSHOT_DISCRIMINATOR, TRACKER_ACCOUNT_SPACE},
+    state::{FenerbahceTracker, ScoringMode, SeasonData, SeasonLogRecord},
+};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction, InstructionError},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use solana_program_test::{processor, BanksClient, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account::Account,
+    clock::Clock,
+    rent::Rent,
+    signature::{Keypair, Signer},
+    system_instruction,
+    transaction::{Transaction, TransactionError},
+};
+
+fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let instruction = FenerbahceInstruction::unpack(instruction_data)?;
+    Processor::process(program_id, accounts, instruction)
+}
+
+async fn setup() -> (ProgramTestContext, Pubkey) {
+    let program_id = Pubkey::new_unique();
+    let program_test = ProgramTest::new(
+        "counter_program",
+        program_id,
+        processor!(process_instruction),
+    );
+    (program_test.start_with_context().await, program_id)
+}
+
+/// Sign and send a single instruction, using a fresh blockhash so repeated
+/// identical instructions aren't rejected as duplicates
+async fn send(
+    context: &mut ProgramTestContext,
+    instruction: solana_program::instruction::Instruction,
+) -> Result<(), TransactionError> {
+    let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        recent_blockhash,
+    );
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .map_err(|err| err.unwrap())
+}
+
+async fn fetch_tracker(banks_client: &mut BanksClient, tracker: &Pubkey) -> FenerbahceTracker {
+    let account = banks_client
+        .get_account(*tracker)
+        .await
+        .unwrap()
+        .expect("tracker account should exist");
+    FenerbahceTracker::unpack(&account.data).unwrap()
+}
+
+#[tokio::test]
+async fn test_initialize_writes_exact_bytes() {
+    let (mut context, program_id) = setup().await;
+    let tracker = get_tracker_address(&program_id);
+    let payer = context.payer.pubkey();
+
+    send(&mut context, initialize_tracker(&program_id, &tracker, &payer)).await.unwrap();
+
+    let account = context.banks_client.get_account(tracker).await.unwrap().unwrap();
+
+    // The serialized tracker, zero-padded to the space reserved for a pending authority
+    let mut tracker_data = FenerbahceTracker::new();
+    tracker_data.authority = payer;
+    let mut expected = borsh::to_vec(&tracker_data).unwrap();
+    expected.resize(TRACKER_ACCOUNT_SPACE, 0);
+
+    assert_eq!(account.owner, program_id);
+    assert_eq!(account.data.len(), FenerbahceTracker::SIZE);
+    assert_eq!(account.data, expected);
+}
+
+#[tokio::test]
+async fn test_initialize_and_play_all_seasons() {
+    let (mut context, program_id) = setup().await;
+    let tracker = get_tracker_address(&program_id);
+    let payer = context.payer.pubkey();
+
+    send(&mut context, initialize_tracker(&program_id, &tracker, &payer)).await.unwrap();
+
+    let mut expected = FenerbahceTracker::new();
+    expected.authority = payer;
+    assert_eq!(fetch_tracker(&mut context.banks_client, &tracker).await, expected);
+
+    for _ in FenerbahceTracker::STARTING_SEASON..=FenerbahceTracker::ENDING_SEASON {
+        send(&mut context, play_season(&program_id, &tracker)).await.unwrap();
+    }
+
+    let final_tracker = fetch_tracker(&mut context.banks_client, &tracker).await;
+    assert_eq!(final_tracker.total_trophies, 19);
+    assert!(final_tracker.is_season_complete());
+}
+
+#[tokio::test]
+async fn test_initialize_twice_fails() {
+    let (mut context, program_id) = setup().await;
+    let tracker = get_tracker_address(&program_id);
+    let payer = context.payer.pubkey();
+
+    send(&mut context, initialize_tracker(&program_id, &tracker, &payer)).await.unwrap();
+
+    let err = send(&mut context, initialize_tracker(&program_id, &tracker, &payer))
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::AccountAlreadyInitialized)
+    );
+}
+
+#[tokio::test]
+async fn test_initialize_if_needed_is_idempotent() {
+    let (mut context, program_id) = setup().await;
+    let tracker = get_tracker_address(&program_id);
+    let payer = context.payer.pubkey();
+
+    send(&mut context, initialize_if_needed(&program_id, &tracker, &payer)).await.unwrap();
+    send(&mut context, play_season(&program_id, &tracker)).await.unwrap();
+    let before = fetch_tracker(&mut context.banks_client, &tracker).await;
+
+    // The second call succeeds without resetting the played season
+    send(&mut context, initialize_if_needed(&program_id, &tracker, &payer)).await.unwrap();
+
+    let after = fetch_tracker(&mut context.banks_client, &tracker).await;
+    assert_eq!(after, before);
+    assert_eq!(after.current_season, 2011);
+}
+
+#[tokio::test]
+async fn test_initialize_and_play_first_season() {
+    let (mut context, program_id) = setup().await;
+    let tracker = get_tracker_address(&program_id);
+    let payer = context.payer.pubkey();
+
+    send(&mut context, initialize_and_play(&program_id, &tracker, &payer)).await.unwrap();
+
+    let tracker_data = fetch_tracker(&mut context.banks_client, &tracker).await;
+    assert_eq!(tracker_data.current_season, 2011);
+    assert_eq!(tracker_data.seasons_played, 1);
+    assert_eq!(tracker_data.total_trophies, 18);
+    assert_eq!(tracker_data.authority, payer);
+
+    // Like InitializeTracker, it can't run against an existing tracker
+    let err = send(&mut context, initialize_and_play(&program_id, &tracker, &payer)).await.unwrap_err();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(0, InstructionError::AccountAlreadyInitialized)
+    );
+}
+
+#[tokio::test]
+async fn test_initialize_with_glory_mode() {
+    let (mut context, program_id) = setup().await;
+    let tracker = get_tracker_address(&program_id);
+    let payer = context.payer.pubkey();
+
+    send(
+        &mut context,
+        initialize_tracker_with_mode(&program_id, &tracker, &payer, ScoringMode::GloryMode),
+    )
+    .await
+    .unwrap();
+
+    let mut expected = FenerbahceTracker::new_with_mode(ScoringMode::GloryMode);
+    expected.authority = payer;
+    assert_eq!(fetch_tracker(&mut context.banks_client, &tracker).await, expected);
+}
+
+#[tokio::test]
+async fn test_initialize_funds_rent_exemption() {
+    let (mut context, program_id) = setup().await;
+    let tracker = get_tracker_address(&program_id);
+    let payer = context.payer.pubkey();
+
+    send(&mut context, initialize_tracker(&program_id, &tracker, &payer)).await.unwrap();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let required_lamports = rent.minimum_balance(TRACKER_ACCOUNT_SPACE);
+    assert!(required_lamports > 0);
+
+    let account = context.banks_client.get_account(tracker).await.unwrap().unwrap();
+    assert_eq!(account.data.len(), TRACKER_ACCOUNT_SPACE);
+    assert_eq!(account.lamports, required_lamports);
+}
+
+/// Create a blank program-owned account with room for a snapshot
+async fn create_snapshot_account(context: &mut ProgramTestContext, program_id: &Pubkey) -> Pubkey {
+    let snapshot_account = Keypair::new();
+    let snapshot_space = 16 + TRACKER_ACCOUNT_SPACE;
+    let payer = context.payer.pubkey();
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+    let transaction = Transaction::new_signed_with_payer(
+        &[system_instruction::create_account(
+            &payer,
+            &snapshot_account.pubkey(),
+            rent.minimum_balance(snapshot_space),
+            snapshot_space as u64,
+            program_id,
+        )],
+        Some(&payer),
+        &[&context.payer, &snapshot_account],
+        recent_blockhash,
+    );
+    context.banks_client.process_transaction(transaction).await.unwrap();
+    snapshot_account.pubkey()
+}
+
+#[tokio::test]
+async fn test_snapshot_copies_slot_and_tracker_bytes() {
+    let (mut context, program_id) = setup().await;
+    let tracker = get_tracker_address(&program_id);
+    let payer = context.payer.pubkey();
+
+    send(&mut context, initialize_tracker(&program_id, &tracker, &payer)).await.unwrap();
+    send(&mut context, play_season(&program_id, &tracker)).await.unwrap();
+
+    // The snapshot account is created separately and handed to the program
+    let snapshot_account = create_snapshot_account(&mut context, &program_id).await;
+    send(&mut context, snapshot(&program_id, &tracker, &snapshot_account, &payer)).await.unwrap();
+
+    let clock: Clock = context.banks_client.get_sysvar().await.unwrap();
+    let tracker_data = context.banks_client.get_account(tracker).await.unwrap().unwrap().data;
+    let snapshot_data = context.banks_client.get_account(snapshot_account).await.unwrap().unwrap().data;
+
+    assert_eq!(&snapshot_data[..8], SNAPSHOT_DISCRIMINATOR);
+    assert_eq!(u64::from_le_bytes(snapshot_data[8..16].try_into().unwrap()), clock.slot);
+    assert_eq!(&snapshot_data[16..], tracker_data.as_slice());
+    assert_eq!(FenerbahceTracker::unpack(&snapshot_data[16..]).unwrap().current_season, 2011);
+
+    // An earlier snapshot can be refreshed
+    send(&mut context, play_season(&program_id, &tracker)).await.unwrap();
+    send(&mut context, snapshot(&program_id, &tracker, &snapshot_account, &payer)).await.unwrap();
+    let snapshot_data = context.banks_client.get_account(snapshot_account).await.unwrap().unwrap().data;
+    assert_eq!(FenerbahceTracker::unpack(&snapshot_data[16..]).unwrap().current_season, 2012);
+}
+
+#[tokio::test]
+async fn test_snapshot_requires_authority() {
+    let (mut context, program_id) = setup().await;
+    let tracker = get_tracker_address(&program_id);
+    let payer = context.payer.pubkey();
+
+    send(&mut context, initialize_tracker(&program_id, &tracker, &payer)).await.unwrap();
+    let snapshot_account = create_snapshot_account(&mut context, &program_id).await;
+
+    // Signed, but by someone other than the authority
+    let stranger = Keypair::new();
+    let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+    let transaction = Transaction::new_signed_with_payer(
+        &[snapshot(&program_id, &tracker, &snapshot_account, &stranger.pubkey())],
+        Some(&payer),
+        &[&context.payer, &stranger],
+        recent_blockhash,
+    );
+    let err = context.banks_client.process_transaction(transaction).await.unwrap_err().unwrap();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(FenerbahceError::UnauthorizedAuthority.code())
+        )
+    );
+}
+
+#[tokio::test]
+async fn test_snapshot_rejects_season_log() {
+    let (mut context, program_id) = setup().await;
+    let tracker = get_tracker_address(&program_id);
+    let season_log = get_season_log_address(&program_id);
+    let payer = context.payer.pubkey();
+
+    send(&mut context, initialize_tracker(&program_id, &tracker, &payer)).await.unwrap();
+    send(&mut context, play_season_logged(&program_id, &tracker, &payer)).await.unwrap();
+    let log_before = context.banks_client.get_account(season_log).await.unwrap().unwrap().data;
+
+    let err = send(&mut context, snapshot(&program_id, &tracker, &season_log, &payer)).await.unwrap_err();
+    assert_eq!(err, TransactionError::InstructionError(0, InstructionError::InvalidArgument));
+
+    let log_after = context.banks_client.get_account(season_log).await.unwrap().unwrap().data;
+    assert_eq!(log_after, log_before);
+}
+
+#[test]
+fn test_run_all_over_reports_each_season() {
+    // `run_all_over` is synchronous, so the banks client is driven from a runtime owned here
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let (mut context, program_id) = runtime.block_on(setup());
+    let tracker = get_tracker_address(&program_id);
+    let payer = context.payer.pubkey();
+
+    runtime.block_on(send(&mut context, initialize_tracker(&program_id, &tracker, &payer))).unwrap();
+    let initial = runtime.block_on(fetch_tracker(&mut context.banks_client, &tracker));
+
+    let play = |instruction| {
+        runtime.block_on(async {
+            send(&mut context, instruction).await?;
+            Ok::<_, TransactionError>(fetch_tracker(&mut context.banks_client, &tracker).await)
+        })
+    };
+    let mut rows = Vec::new();
+    let final_tracker = run_all_over(&program_id, initial, play, |tracker, season| {
+        rows.push((season.season, tracker.total_trophies));
+    })
+    .unwrap();
+
+    assert_eq!(rows.len(), SeasonData::SEASONS.len());
+    assert_eq!(rows.first(), Some(&(2010, 18)));
+    assert_eq!(rows.last(), Some(&(FenerbahceTracker::ENDING_SEASON, 19)));
+    assert!(final_tracker.is_season_complete());
+}
+
+#[tokio::test]
+async fn test_initialize_missing_payer_is_named_in_logs() {
+    let (mut context, program_id) = setup().await;
+    let tracker = get_tracker_address(&program_id);
+
+    // Only the tracker account, no payer or system program
+    let instruction = Instruction {
+        program_id,
+        accounts: vec![AccountMeta::new(tracker, false)],
+        data: vec![0],
+    };
+    let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        recent_blockhash,
+    );
+
+    let simulation = context.banks_client.simulate_transaction(transaction).await.unwrap();
+
+    assert_eq!(
+        simulation.result.unwrap().unwrap_err(),
+        TransactionError::InstructionError(0, InstructionError::NotEnoughAccountKeys)
+    );
+    let logs = simulation.simulation_details.unwrap().logs;
+    assert!(logs.iter().any(|log| log.contains("Missing payer account")), "{:?}", logs);
+}
+
+/// Start a program test whose tracker PDA already holds a legacy 11-byte account, along
+/// with a separate rent payer funded with `payer_lamports`
+async fn setup_legacy_tracker(payer_lamports: u64) -> (ProgramTestContext, Pubkey, Keypair) {
+    let program_id = Pubkey::new_unique();
+    let mut program_test = ProgramTest::new(
+        "counter_program",
+        program_id,
+        processor!(process_instruction),
+    );
+
+    // total_trophies: u64, current_season: u16, seasons_played: u8
+    let mut legacy = 18u64.to_le_bytes().to_vec();
+    legacy.extend_from_slice(&2012u16.to_le_bytes());
+    legacy.push(2);
+
+    let rent = Rent::default();
+    program_test.add_account(
+        get_tracker_address(&program_id),
+        Account {
+            lamports: rent.minimum_balance(legacy.len()),
+            data: legacy,
+            owner: program_id,
+            ..Account::default()
+        },
+    );
+
+    let rent_payer = Keypair::new();
+    program_test.add_account(
+        rent_payer.pubkey(),
+        Account { lamports: payer_lamports, ..Account::default() },
+    );
+
+    (program_test.start_with_context().await, program_id, rent_payer)
+}
+
+/// Send `instruction` signed by the context payer (for fees) and `signer`
+async fn send_with_signer(
+    context: &mut ProgramTestContext,
+    instruction: Instruction,
+    signer: &Keypair,
+) -> Result<(), TransactionError> {
+    let recent_blockhash = context.get_new_latest_blockhash().await.unwrap();
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, signer],
+        recent_blockhash,
+    );
+    context
+        .banks_client
+        .process_transaction(transaction)
+        .await
+        .map_err(|err| err.unwrap())
+}
+
+#[tokio::test]
+async fn test_migrate_tracker_grows_account_and_tops_up_rent() {
+    let (mut context, program_id, rent_payer) = setup_legacy_tracker(1_000_000_000).await;
+    let tracker = get_tracker_address(&program_id);
+
+    send_with_signer(&mut context, migrate_tracker(&program_id, &tracker, &rent_payer.pubkey()), &rent_payer)
+        .await
+        .unwrap();
+
+    let account = context.banks_client.get_account(tracker).await.unwrap().unwrap();
+    let rent = context.banks_client.get_rent().await.unwrap();
+    assert_eq!(account.data.len(), TRACKER_ACCOUNT_SPACE);
+    assert!(rent.is_exempt(account.lamports, account.data.len()));
+
+    // Paying the rent doesn't make the payer the authority
+    let migrated = FenerbahceTracker::unpack(&account.data).unwrap();
+    assert_eq!(migrated, FenerbahceTracker::replayed_to(2012).unwrap());
+    assert_eq!(migrated.authority, Pubkey::default());
+    assert_ne!(migrated.authority, rent_payer.pubkey());
+    let err = send_with_signer(&mut context, pause(&program_id, &tracker, &rent_payer.pubkey()), &rent_payer)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(FenerbahceError::UnauthorizedAuthority.code())
+        )
+    );
+
+    // The migrated tracker plays on from where the legacy one stopped
+    send(&mut context, play_season(&program_id, &tracker)).await.unwrap();
+    assert_eq!(fetch_tracker(&mut context.banks_client, &tracker).await.current_season, 2013);
+
+    // Migrating again is a no-op
+    send_with_signer(&mut context, migrate_tracker(&program_id, &tracker, &rent_payer.pubkey()), &rent_payer)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_migrate_tracker_rejects_underfunded_payer() {
+    // Enough to stay rent-exempt, but not to also cover the tracker's growth
+    let (mut context, program_id, rent_payer) =
+        setup_legacy_tracker(Rent::default().minimum_balance(0) + 1).await;
+    let tracker = get_tracker_address(&program_id);
+
+    let err = send_with_signer(&mut context, migrate_tracker(&program_id, &tracker, &rent_payer.pubkey()), &rent_payer)
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(FenerbahceError::InsufficientRentFunds.code())
+        )
+    );
+
+    let account = context.banks_client.get_account(tracker).await.unwrap().unwrap();
+    assert_eq!(account.data.len(), FenerbahceTracker::LEGACY_SIZE);
+}
+
+/// Mock reward program: appends each TitleWon season to the record account it owns
+fn mock_reward_process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let title = TitleWon::decode(instruction_data).ok_or(ProgramError::InvalidInstructionData)?;
+    let (tracker, record) = (&accounts[0], &accounts[1]);
+    if !tracker.is_signer || record.owner != program_id {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Record layout: count (u8) followed by each notified season (u16 LE)
+    let mut data = record.try_borrow_mut_data()?;
+    let count = data[0] as usize;
+    data[1 + 2 * count..3 + 2 * count].copy_from_slice(&title.season.to_le_bytes());
+    data[0] += 1;
+    Ok(())
+}
+
+/// A test bank with the mock reward program and an empty record account it owns, returning
+/// `(context, program_id, reward_program, record)`
+async fn setup_with_reward_program() -> (ProgramTestContext, Pubkey, Pubkey, Pubkey) {
+    let program_id = Pubkey::new_unique();
+    let reward_program = Pubkey::new_unique();
+    let record = Pubkey::new_unique();
+
+    let mut program_test = ProgramTest::new(
+        "counter_program",
+        program_id,
+        processor!(process_instruction),
+    );
+    program_test.add_program("mock_reward", reward_program, processor!(mock_reward_process));
+    program_test.add_account(
+        record,
+        Account {
+            lamports: Rent::default().minimum_balance(9),
+            data: vec![0; 9],
+            owner: reward_program,
+            ..Account::default()
+        },
+    );
+    (program_test.start_with_context().await, program_id, reward_program, record)
+}
+
+#[tokio::test]
+async fn test_play_season_notifies_reward_program_on_titles_only() {
+    let (mut context, program_id, reward_program, record) = setup_with_reward_program().await;
+    let tracker = get_tracker_address(&program_id);
+    let payer = context.payer.pubkey();
+
+    send(&mut context, initialize_tracker(&program_id, &tracker, &payer)).await.unwrap();
+    send(&mut context, set_reward_program(&program_id, &tracker, &payer, Some(reward_program))).await.unwrap();
+
+    // 2010 and 2013 are title seasons, 2011 and 2012 aren't
+    for _ in 2010..=2013 {
+        let ix = play_season_with_reward_hook(
+            &program_id,
+            &tracker,
+            &reward_program,
+            vec![AccountMeta::new(record, false)],
+        );
+        send(&mut context, ix).await.unwrap();
+    }
+
+    let record_account = context.banks_client.get_account(record).await.unwrap().unwrap();
+    assert_eq!(record_account.data[0], 2);
+    assert_eq!(&record_account.data[1..5], [2010u16.to_le_bytes(), 2013u16.to_le_bytes()].concat());
+    assert_eq!(fetch_tracker(&mut context.banks_client, &tracker).await.current_season, 2014);
+}
+
+#[tokio::test]
+async fn test_play_season_rejects_unregistered_reward_program() {
+    let (mut context, program_id, reward_program, record) = setup_with_reward_program().await;
+    let tracker = get_tracker_address(&program_id);
+    let payer = context.payer.pubkey();
+    let unregistered = Err(TransactionError::InstructionError(
+        0,
+        InstructionError::Custom(FenerbahceError::UnregisteredRewardProgram.code()),
+    ));
+    let play_with = |program: &Pubkey| {
+        play_season_with_reward_hook(&program_id, &tracker, program, vec![AccountMeta::new(record, false)])
+    };
+
+    send(&mut context, initialize_tracker(&program_id, &tracker, &payer)).await.unwrap();
+
+    // No reward program registered yet, so even the mock is refused the tracker's signature
+    assert_eq!(send(&mut context, play_with(&reward_program)).await, unregistered);
+
+    // Registering the mock doesn't let any other executable in its place
+    send(&mut context, set_reward_program(&program_id, &tracker, &payer, Some(reward_program))).await.unwrap();
+    let system_program = solana_program::system_program::id();
+    assert_eq!(send(&mut context, play_with(&system_program)).await, unregistered);
+
+    let record_account = context.banks_client.get_account(record).await.unwrap().unwrap();
+    assert_eq!(record_account.data[0], 0);
+    assert_eq!(fetch_tracker(&mut context.banks_client, &tracker).await.current_season, 2010);
+}
+
+#[tokio::test]
+async fn test_airdrop_shortfall_against_bank_balances() {
+    let funded = Pubkey::new_unique();
+    let empty = Pubkey::new_unique();
+    let min_lamports = 1_000_000;
+
+    let mut program_test = ProgramTest::default();
+    program_test.add_account(funded, Account { lamports: 2 * min_lamports, ..Account::default() });
+    let mut context = program_test.start_with_context().await;
+
+    let funded_balance = context.banks_client.get_balance(funded).await.unwrap();
+    assert_eq!(airdrop_shortfall(funded_balance, min_lamports), None);
+
+    let empty_balance = context.banks_client.get_balance(empty).await.unwrap();
+    assert_eq!(airdrop_shortfall(empty_balance, min_lamports), Some(min_lamports));
+}
+
+#[tokio::test]
+async fn test_play_season_logged_appends_records() {
+    let (mut context, program_id) = setup().await;
+    let tracker = get_tracker_address(&program_id);
+    let payer = context.payer.pubkey();
+
+    send(&mut context, initialize_tracker(&program_id, &tracker, &payer)).await.unwrap();
+    for _ in 0..3 {
+        send(&mut context, play_season_logged(&program_id, &tracker, &payer)).await.unwrap();
+    }
+
+    let log = context
+        .banks_client
+        .get_account(get_season_log_address(&program_id))
+        .await
+        .unwrap()
+        .expect("season log should exist");
+    assert_eq!(log.owner, program_id);
+    assert_eq!(log.data.len(), 3 * SeasonLogRecord::SIZE);
+    assert!(log.lamports >= Rent::default().minimum_balance(log.data.len()));
+    assert_eq!(
+        decode_season_log(&log.data),
+        vec![
+            SeasonLogRecord { season: 2010, position: 1, champion: true },
+            SeasonLogRecord { season: 2011, position: 2, champion: false },
+            SeasonLogRecord { season: 2012, position: 2, champion: false },
+        ]
+    );
+    assert_eq!(fetch_tracker(&mut context.banks_client, &tracker).await.current_season, 2013);
+}
+
+#[tokio::test]
+async fn test_play_season_logged_with_prefunded_log() {
+    let (mut context, program_id) = setup().await;
+    let tracker = get_tracker_address(&program_id);
+    let season_log = get_season_log_address(&program_id);
+    let payer = context.payer.pubkey();
+
+    // Anyone can send lamports to the log address before its first use
+    send(&mut context, system_instruction::transfer(&payer, &season_log, 1_000_000)).await.unwrap();
+    send(&mut context, initialize_tracker(&program_id, &tracker, &payer)).await.unwrap();
+    send(&mut context, play_season_logged(&program_id, &tracker, &payer)).await.unwrap();
+
+    let log = context.banks_client.get_account(season_log).await.unwrap().expect("season log should exist");
+    assert_eq!(log.owner, program_id);
+    assert!(log.lamports >= Rent::default().minimum_balance(log.data.len()));
+    assert_eq!(decode_season_log(&log.data), vec![SeasonLogRecord { season: 2010, position: 1, champion: true }]);
+}
+
+/// RPC transport answering rent queries from a bank's `Rent`, recording the spaces asked for
+#[cfg(feature = "client-rpc")]
+struct BankRentSender {
+    rent: Rent,
+    requested: std::sync::Arc<std::sync::Mutex<Vec<usize>>>,
+}
+
+#[cfg(feature = "client-rpc")]
+#[async_trait::async_trait]
+impl solana_client::rpc_sender::RpcSender for BankRentSender {
+    async fn send(
+        &self,
+        request: solana_client::rpc_request::RpcRequest,
+        params: serde_json::Value,
+    ) -> solana_client::client_error::Result<serde_json::Value> {
+        assert_eq!(request, solana_client::rpc_request::RpcRequest::GetMinimumBalanceForRentExemption);
+        let space = params[0].as_u64().unwrap() as usize;
+        self.requested.lock().unwrap().push(space);
+        Ok(self.rent.minimum_balance(space).into())
+    }
+
+    fn get_transport_stats(&self) -> solana_client::rpc_sender::RpcTransportStats {
+        Default::default()
+    }
+
+    fn url(&self) -> String {
+        "bank-rent".to_string()
+    }
+}
+
+#[cfg(feature = "client-rpc")]
+#[test]
+fn test_required_init_lamports_matches_created_tracker() {
+    use solana_client::rpc_client::{RpcClient, RpcClientConfig};
+
+    // The blocking RpcClient runs its own runtime, so it can't be called from inside a tokio test
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let (mut context, program_id) = runtime.block_on(setup());
+    let tracker = get_tracker_address(&program_id);
+    let payer = context.payer.pubkey();
+
+    runtime.block_on(send(&mut context, initialize_tracker(&program_id, &tracker, &payer))).unwrap();
+    let account = runtime.block_on(context.banks_client.get_account(tracker)).unwrap().unwrap();
+    let rent = runtime.block_on(context.banks_client.get_rent()).unwrap();
+
+    let requested = std::sync::Arc::default();
+    let sender = BankRentSender { rent, requested: std::sync::Arc::clone(&requested) };
+    let rpc = RpcClient::new_sender(sender, RpcClientConfig::default());
+    let lamports = required_init_lamports(&rpc).unwrap();
+
+    assert_eq!(*requested.lock().unwrap(), vec![TRACKER_ACCOUNT_SPACE]);
+    assert_eq!(account.data.len(), TRACKER_ACCOUNT_SPACE);
+    assert!(lamports > 0);
+    assert_eq!(lamports, rent.minimum_balance(TRACKER_ACCOUNT_SPACE));
+    assert_eq!(lamports, account.lamports);
+}